@@ -1,10 +1,11 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractimpl, contracttype, contracterror,
-    Address, Env, Vec,Bytes, BytesN, IntoVal, contractclient, vec
+    contract, contractimpl, contracttype, contracterror, contractevent,
+    Address, Env, Vec,Bytes, BytesN, IntoVal, contractclient, vec, String
 };
 use soroban_sdk::panic_with_error;
+use soroban_sdk::xdr::ToXdr;
 
 
 use core::option::Option;
@@ -32,6 +33,15 @@ pub trait GameHub {
     );
 }
 
+/* ------------------------------------------------ */
+/*                  VERIFIER CLIENT                 */
+/* ------------------------------------------------ */
+
+#[contractclient(name = "VerifierClient")]
+pub trait Verifier {
+    fn verify(env: Env, proof: Bytes, public_inputs: Vec<u32>) -> bool;
+}
+
 /* ------------------------------------------------ */
 /*                      ERRORS                      */
 /* ------------------------------------------------ */
@@ -47,6 +57,44 @@ pub enum Error {
     GameAlreadyEnded = 5,
     InvalidGuessLength = 6,
     AlreadyCommitted = 7,
+    BothPlayersNotCommitted = 8,
+    InvalidHub = 9,
+    ProofAlreadyUsed = 10,
+    GameNotSettled = 11,
+    CooldownActive = 12,
+    ResultNotConfirmed = 13,
+    HintsDisabled = 14,
+    DelegateExpired = 15,
+    RateLimited = 16,
+    NoSuitableWord = 17,
+    GameExpired = 18,
+    WordNotReported = 19,
+    NotOracle = 20,
+    PotTooLarge = 21,
+    NewGamesDisabled = 22,
+    InvalidGuessValue = 23,
+    CommitmentMismatch = 24,
+    InvalidLetter = 25,
+    InvalidProof = 26,
+    ProofInputMismatch = 27,
+    TimeoutNotReached = 28,
+    GameAlreadyStarted = 29,
+    CommitmentRequired = 30,
+    InsufficientBankroll = 31,
+    InvalidWord = 32,
+    RoundAlreadyComplete = 33,
+    MatchNotOver = 34,
+    SessionNotReserved = 35,
+    InvalidWinnerFlag = 36,
+    ContractPaused = 37,
+    ProtocolMixing = 38,
+    DrawNotReportable = 40,
+    DelegateInUse = 41,
+    InvalidAmount = 42,
+    OpponentNotBehind = 43,
+    WordAlreadyReported = 44,
+    NotADraw = 45,
+    TooManyRounds = 46,
 }
 
 /* ------------------------------------------------ */
@@ -59,6 +107,218 @@ pub enum DataKey {
     Game(u32),
     GameHubAddress,
     Admin,
+    TtlPolicy,
+    UsedProof(BytesN<32>),
+    Cooldown,
+    LastGameLedger(Address),
+    WordStats(u32),
+    ScoreWeights,
+    Delegate(Address),
+    GamesThisLedger(u32),
+    MaxGamesPerLedger,
+    PlayerProfile(Address),
+    MaxSession,
+    Oracle,
+    MaxPot,
+    DrainMode,
+    AutoPurgeOnEnd,
+    Verifier,
+    MoveTimeout,
+    StatusCounts,
+    House,
+    HouseBankroll,
+    Words,
+    NextSession,
+    ReservedSession(u32),
+    RequireReservation,
+    WordsScoringMode,
+    PlayerGames(Address),
+    Paused,
+    MinDistinctLetters,
+    EventLevel,
+    RecentSettled,
+    MaxRounds,
+}
+
+/* ------------------------------------------------ */
+/*                   GAME OPTIONS                   */
+/* ------------------------------------------------ */
+
+/// Which side of the letter count wins a game
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScoringDirection {
+    /// More correct letters wins (default)
+    Higher,
+    /// Fewer correct letters wins ("golf" mode)
+    Lower,
+}
+
+/// Which matches count towards a guess's score
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScoringMode {
+    /// Letters anywhere in the word count (default)
+    Loose,
+    /// Only letters in the correct position count (Mastermind-style)
+    Exact,
+}
+
+/// When plaintext guesses may be revealed in a commit/reveal game
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RevealOrder {
+    /// No guess is revealed until both players have committed (default)
+    Simultaneous,
+    /// Guesses are visible as soon as they are submitted
+    Sequential,
+}
+
+/// Per-game options, fixed when the game starts
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameConfig {
+    pub scoring_direction: ScoringDirection,
+    pub scoring_mode: ScoringMode,
+    /// Both players must `confirm_result` before `end_game` reports to the hub
+    pub require_confirmation: bool,
+    /// A plaintext guess matching every position of the hidden word wins
+    /// immediately, without waiting for the opponent
+    pub early_settle_on_perfect: bool,
+    /// Pre-settlement hints such as `guess_overlap` are available
+    pub hints_enabled: bool,
+    /// Letters per guess. The hidden word must have at least this many
+    /// distinct letters so a full match is achievable (0 = the hidden
+    /// word's length)
+    pub guess_length: u32,
+    /// Random letters outside the word added to the hidden set; guessing
+    /// one counts as "present", misleading players
+    pub decoys: u32,
+    /// Score against letters reported by the registered oracle instead of
+    /// the built-in word pool
+    pub use_oracle: bool,
+    /// Reveal policy once either player has committed (games without
+    /// commitments are always sequential)
+    pub reveal_order: RevealOrder,
+    /// Rounds in a best-of-N match; the first to win a majority takes the
+    /// match (0 or 1 = a single round, at most `get_max_rounds`)
+    pub rounds: u32,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        GameConfig {
+            scoring_direction: ScoringDirection::Higher,
+            scoring_mode: ScoringMode::Loose,
+            require_confirmation: false,
+            early_settle_on_perfect: false,
+            hints_enabled: true,
+            guess_length: 0,
+            decoys: 0,
+            use_oracle: false,
+            reveal_order: RevealOrder::Simultaneous,
+            rounds: 1,
+        }
+    }
+}
+
+/// Outcome of a settled game
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GameResult {
+    Player1Win,
+    Player2Win,
+    /// Equal scores: each player keeps their own stake
+    Draw,
+}
+
+/// Why a game was settled the way it was
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ResultReason {
+    /// The winner had the better score
+    Score,
+    /// Scores were equal, so the game is a draw
+    Draw,
+    /// The winner's guess matched the hidden word exactly (early settlement)
+    PerfectMatch,
+    /// Settled from a zero-knowledge proof; component scores are not known
+    Proof,
+    /// The opponent didn't move before the timeout (see `claim_timeout_win`)
+    Timeout,
+    /// The opponent conceded (see `surrender`)
+    Surrender,
+}
+
+/// Component scores of a settled game, stored at settlement
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResultBreakdown {
+    pub p1_exact: u32,
+    pub p1_present: u32,
+    pub p2_exact: u32,
+    pub p2_present: u32,
+    /// `None` for a draw
+    pub winner: Option<Address>,
+    pub reason: ResultReason,
+}
+
+/// Where a game is in its lifecycle (see `get_game_status`)
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GameStatus {
+    /// A player hasn't committed or guessed yet
+    WaitingForCommits,
+    /// Both players have moved, but a committed guess is still unrevealed
+    WaitingForReveals,
+    /// Both guesses are in; `reveal_winner` can settle the game
+    ReadyToSettle,
+    Settled,
+    /// No such game, or it expired
+    NotFound,
+}
+
+/// What `reveal_winner_detailed` reports to the UI
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RevealOutcome {
+    /// `None` for a draw (or a best-of-N match that isn't over yet)
+    pub winner: Option<Address>,
+    /// Letters each player got right under the game's scoring mode
+    pub player1_correct: u32,
+    pub player2_correct: u32,
+    pub is_draw: bool,
+}
+
+/// A session key allowed to act for a player until `expires_at` (ledger)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Delegation {
+    pub player: Address,
+    pub expires_at: u32,
+}
+
+/// Aggregate results of all settled games for one hidden word
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct WordStats {
+    pub games: u32,
+    pub p1_wins: u32,
+    pub p2_wins: u32,
+    pub draws: u32,
+}
+
+/// Lifetime results of one player across all settled games
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlayerProfile {
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    /// Net points won minus points lost
+    pub pnl: i128,
+    /// Total points won from opponents
+    pub total_won: i128,
 }
 
 /* ------------------------------------------------ */
@@ -77,11 +337,71 @@ pub struct Game {
     pub player2_points: i128,
 
     pub winner: Option<Address>,
+    /// Outcome once settled; `winner` stays `None` on a draw
+    pub result: Option<GameResult>,
 
     pub hidden_word_id: u32,
+    /// The pool word's letters, taken when the word is drawn so later edits
+    /// to the pool don't change a game in play
+    pub hidden_letters: Vec<u32>,
+
+    /// Letters each guess must have
+    pub guess_length: u32,
 
     pub player1_guess_commitment: Option<BytesN<32>>,
-    pub player2_guess_commitment: Option<BytesN<32>>
+    pub player2_guess_commitment: Option<BytesN<32>>,
+
+    /// Ledger sequence the game was started at
+    pub start_ledger: u32,
+
+    /// Ledger sequence at which the winner was decided
+    pub settled_at_ledger: Option<u32>,
+
+    pub config: GameConfig,
+
+    /// Set once `end_game` has reported the result to the Game Hub
+    pub ended: bool,
+
+    /// Mutual confirmation of the provisional winner (see `GameConfig`)
+    pub player1_confirmed: bool,
+    pub player2_confirmed: bool,
+
+    /// Component scores, set when the game is settled
+    pub breakdown: Option<ResultBreakdown>,
+
+    /// Ledger sequence of the last write to this game
+    pub last_action_ledger: u32,
+
+    /// Seed the decoy letters are derived from, kept for auditability
+    pub decoy_seed: Option<BytesN<32>>,
+
+    /// Oracle expected to report the word (games started with `use_oracle`)
+    pub oracle: Option<Address>,
+    /// Letters reported by the oracle; replace the pool word once set
+    pub oracle_letters: Option<Vec<u32>>,
+
+    /// Salts revealed with `reveal_guess`, checked against the commitments
+    pub player1_salt: Option<BytesN<32>>,
+    pub player2_salt: Option<BytesN<32>>,
+
+    /// Points the loser handed to the winner at settlement (0 on a draw)
+    pub forfeited: i128,
+
+    /// Player2 is the house (see `start_vs_house`); its points return to
+    /// the bankroll at settlement
+    pub vs_house: bool,
+
+    /// Best-of-N match state (see `GameConfig::rounds`); single-round games
+    /// have `rounds == 1` and never use the rest
+    pub rounds: u32,
+    /// 1-based round currently being played
+    pub current_round: u32,
+    pub p1_round_wins: u32,
+    pub p2_round_wins: u32,
+    /// The current round has been scored; `next_round` starts the next one
+    pub round_complete: bool,
+    /// Times the game was replayed after a draw (see `replay_draw`)
+    pub replays: u32,
 }
 
 // ============================================================================
@@ -94,6 +414,92 @@ pub struct Game {
 /// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
 const GAME_TTL_LEDGERS: u32 = 518_400;
 
+/// Words hidden words are drawn from, indexed by `hidden_word_id`. The
+/// frontend keeps an exact copy, so only ever append.
+const WORD_POOL: [&str; 50] = [
+    "APPLE", "BANANA", "ORANGE", "GRAPE", "MANGO",
+    "PEACH", "LEMON", "CHERRY", "PEAR", "PLUM",
+    "KIWI", "FIG", "DATE", "LIME", "APRICOT",
+    "PAPAYA", "GUAVA", "PINEAPPLE", "COCONUT", "BLUEBERRY",
+    "STRAWBERRY", "RASPBERRY", "BLACKBERRY", "WATERMELON", "CANTALOUPE",
+    "HONEYDEW", "NECTARINE", "TANGERINE", "POMEGRANATE", "PASSIONFRUIT",
+    "DRAGONFRUIT", "LYCHEE", "JACKFRUIT", "CRANBERRY", "MULBERRY",
+    "FIGS", "DATEFRUIT", "OLIVE", "QUINCE", "KUMQUAT",
+    "AVOCADO", "MANDARIN", "PEPPERMINT", "CLEMENTINE", "GRAPEFRUIT",
+    "STARFRUIT", "BILBERRY", "GOOSEBERRY", "ELDERBERRY", "SATSUMA",
+];
+
+/// Letters per guess for oracle games that don't configure `guess_length`
+const DEFAULT_GUESS_LENGTH: u32 = 3;
+
+/// Longest word accepted by `make_guess_str`
+const MAX_GUESS_STR_LEN: usize = 32;
+
+/// Ledgers a player has to move before the opponent may claim the game
+/// (1 day), unless the admin configures another timeout
+const DEFAULT_MOVE_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Most rounds a best-of-N match may be started with, unless the admin
+/// configures another cap
+const DEFAULT_MAX_ROUNDS: u32 = 9;
+
+/// Indices into the `status_counts` tuple
+const STATUS_WAITING: u32 = 0;
+const STATUS_BOTH_GUESSED: u32 = 1;
+const STATUS_SETTLED: u32 = 2;
+const STATUS_CANCELLED: u32 = 3;
+
+/// Tags keeping the PRNG draws of one round apart (see `seed_prng`)
+const SEED_TAG_WORD: u8 = 0;
+const SEED_TAG_HOUSE: u8 = 1;
+
+/// Settled session IDs kept for `recent_settled`; older ones drop off
+const RECENT_SETTLED_CAPACITY: u32 = 20;
+
+/// Event levels (see `set_event_level`): nothing, `WinnerRevealed` only,
+/// or every event
+const EVENT_LEVEL_NONE: u32 = 0;
+const EVENT_LEVEL_LIFECYCLE: u32 = 1;
+const EVENT_LEVEL_VERBOSE: u32 = 2;
+
+/// Ledgers players have to confirm a result before the admin may settle
+/// the dispute (1 day)
+const CONFIRMATION_WINDOW_LEDGERS: u32 = 17_280;
+
+/* ------------------------------------------------ */
+/*                      EVENTS                      */
+/* ------------------------------------------------ */
+// Topics are the event name and the session ID; the data is a single value.
+// Which events are published depends on the admin's event level.
+
+/// A guess was stored by `make_guess` or `reveal_guess`. Data: the player.
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuessMade {
+    #[topic]
+    pub session_id: u32,
+    pub player: Address,
+}
+
+/// A commitment was stored by `commit_guess`. Data: the player.
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuessCommitted {
+    #[topic]
+    pub session_id: u32,
+    pub player: Address,
+}
+
+/// `reveal_winner` (or `settle`) scored the game. Data: the winner, `None`
+/// on a draw.
+#[contractevent(data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WinnerRevealed {
+    #[topic]
+    pub session_id: u32,
+    pub winner: Option<Address>,
+}
+
 /* ------------------------------------------------ */
 /*                    CONTRACT                      */
 /* ------------------------------------------------ */
@@ -125,14 +531,271 @@ impl AlphaDuelContract {
         player1_points: i128,
         player2_points: i128,
     ) -> Result<(), Error> {
+         // Require authentication from both players (they consent to committing points)
+        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
+        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
+
+        Self::create_game(
+            &env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            Self::pool_default_config(&env),
+        )
+    }
+
+    /* -------------------------------------------- */
+    /* START GAME WITH OPTIONS                      */
+    /* -------------------------------------------- */
+    /// Start a game with non-default options (see `GameConfig`).
+    /// Both players sign over the config together with their points.
+    pub fn start_game_with_config(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        config: GameConfig,
+    ) -> Result<(), Error> {
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1_points.into_val(&env),
+            config.into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player2_points.into_val(&env),
+            config.into_val(&env),
+        ]);
+
+        Self::create_game(
+            &env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            config,
+        )
+    }
+
+    /* -------------------------------------------- */
+    /* START GAME + PLAYER1 COMMITMENT              */
+    /* -------------------------------------------- */
+    /// Start a game and record player1's guess commitment in one call.
+    /// Player1 signs over the commitment together with their points;
+    /// player2 authorizes exactly as for `start_game`.
+    pub fn start_with_commitment(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        player1_commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1_points.into_val(&env),
+            player1_commitment.into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
+
+        Self::create_game(
+            &env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            Self::pool_default_config(&env),
+        )?;
+
+        let key = DataKey::Game(session_id);
+        let mut game = Self::get_game(env.clone(), session_id)?;
+        game.player1_guess_commitment = Some(player1_commitment);
+        Self::save_game(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    /* -------------------------------------------- */
+    /* START GAME VS HOUSE                          */
+    /* -------------------------------------------- */
+    /// Start a solo game against the house. The house matches `stake` out
+    /// of the bankroll. The player guesses in plaintext (`commit_guess` is
+    /// refused); `reveal_winner` then draws the house's guess from the
+    /// seeded PRNG and settles like any other game.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The player taking on the house
+    /// * `stake` - Points staked by the player and matched by the house
+    pub fn start_vs_house(env: Env, session_id: u32, player: Address, stake: i128) -> Result<(), Error> {
+        player.require_auth_for_args(vec![&env, session_id.into_val(&env), stake.into_val(&env)]);
+
+        if stake <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let house: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::House)
+            .expect("House not set");
+
+        let bankroll = Self::get_house_bankroll(env.clone());
+        if stake > bankroll {
+            return Err(Error::InsufficientBankroll);
+        }
+
+        Self::create_game(
+            &env,
+            session_id,
+            player,
+            house,
+            stake,
+            stake,
+            Self::pool_default_config(&env),
+        )?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::HouseBankroll, &(bankroll - stake));
+
+        // The house guesses at settlement, so there's nothing to read early
+        let key = DataKey::Game(session_id);
+        let mut game = Self::get_game(env.clone(), session_id)?;
+        game.vs_house = true;
+        Self::save_game(&env, &key, &mut game);
+
+        Ok(())
+    }
+
+    // Random letters for the house, one per guess letter
+    fn house_guess(env: &Env, session_id: u32, game: &Game) -> Vec<u32> {
+        Self::seed_prng(
+            env,
+            session_id,
+            &game.player1,
+            &game.player2,
+            game.current_round,
+            game.replays,
+            SEED_TAG_HOUSE,
+        );
+        let mut guess = Vec::new(env);
+        for _ in 0..game.guess_length {
+            guess.push_back(env.prng().gen_range::<u64>(0..26) as u32);
+        }
+        guess
+    }
+
+    fn create_game(
+        env: &Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        config: GameConfig,
+    ) -> Result<(), Error> {
+        Self::require_not_paused(env)?;
+
         // Prevent self-play: Player 1 and Player 2 must be different
         if player1 == player2 {
             panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
         }
-         // Require authentication from both players (they consent to committing points)
-        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
-        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
-        
+
+        // Drain mode stops new games; games in progress are unaffected
+        if Self::is_drain_mode(env.clone()) {
+            return Err(Error::NewGamesDisabled);
+        }
+
+        // With reservation required, the id must come from `reserve_session`
+        // and have been reserved by one of the players
+        let reservation_key = DataKey::ReservedSession(session_id);
+        if Self::is_reservation_required(env.clone()) {
+            let reserved_by: Option<Address> = env.storage().temporary().get(&reservation_key);
+            match reserved_by {
+                Some(by) if by == player1 || by == player2 => {}
+                _ => return Err(Error::SessionNotReserved),
+            }
+        }
+
+        // Cap the combined stake (unset = no cap)
+        if let Some(max_pot) = Self::get_max_pot(env.clone()) {
+            if player1_points + player2_points > max_pot {
+                return Err(Error::PotTooLarge);
+            }
+        }
+
+        // Bound the rounds a match can hold
+        if config.rounds > Self::get_max_rounds(env.clone()) {
+            return Err(Error::TooManyRounds);
+        }
+
+        // Enforce the per-player cool-down between games (0 = disabled)
+        let cooldown = Self::get_cooldown(env.clone());
+        if cooldown > 0 {
+            let now = env.ledger().sequence();
+            let house = Self::get_house(env.clone());
+            for player in [&player1, &player2] {
+                // The house plays every house game, so it's never throttled
+                if house.as_ref() == Some(player) {
+                    continue;
+                }
+                let last_key = DataKey::LastGameLedger(player.clone());
+                if let Some(last) = env.storage().persistent().get::<_, u32>(&last_key) {
+                    if now < last.saturating_add(cooldown) {
+                        return Err(Error::CooldownActive);
+                    }
+                }
+                env.storage().persistent().set(&last_key, &now);
+            }
+        }
+
+        // Global per-ledger cap on new games (0 = unlimited). Each ledger
+        // has its own counter, so it resets naturally.
+        let max_games = Self::get_max_games_per_ledger(env.clone());
+        if max_games > 0 {
+            let counter_key = DataKey::GamesThisLedger(env.ledger().sequence());
+            let started: u32 = env.storage().temporary().get(&counter_key).unwrap_or(0);
+            if started >= max_games {
+                return Err(Error::RateLimited);
+            }
+            env.storage().temporary().set(&counter_key, &(started + 1));
+        }
+
+        // Random hidden word, re-rolled if it can't be fully matched
+        Self::seed_prng(env, session_id, &player1, &player2, 1, 0, SEED_TAG_WORD);
+        let hidden_word_id = Self::select_word_id(env, config.guess_length)?;
+
+        let oracle: Option<Address> = if config.use_oracle {
+            Some(
+                env.storage()
+                    .instance()
+                    .get(&DataKey::Oracle)
+                    .expect("Oracle not set"),
+            )
+        } else {
+            None
+        };
+
+        let guess_length = Self::guess_length_for(env, &config, hidden_word_id);
+        let rounds = config.rounds.max(1);
+
+        let decoy_seed = if config.decoys > 0 {
+            Some(env.prng().gen::<BytesN<32>>())
+        } else {
+            None
+        };
+
          // Get GameHub address
         let game_hub_addr: Address = env
             .storage()
@@ -141,7 +804,7 @@ impl AlphaDuelContract {
             .expect("GameHub address not set");
 
         // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = GameHubClient::new(env, &game_hub_addr);
 
         // Call the Game Hub to start the session and lock points
         // This requires THIS contract's authorization (env.current_contract_address())
@@ -154,9 +817,6 @@ impl AlphaDuelContract {
             &player2_points,
         );
 
-        // Random hidden word based on session_id
-        let hidden_word_id = session_id % 50;
-
         let game = Game {
             player1,
             player1_guess: None,
@@ -167,25 +827,109 @@ impl AlphaDuelContract {
             player2_points,
 
             winner: None,
+            result: None,
             hidden_word_id,
+            hidden_letters: Self::get_hidden_letters(env.clone(), hidden_word_id),
+            guess_length,
             player1_guess_commitment: None, 
             player2_guess_commitment: None,
+            start_ledger: env.ledger().sequence(),
+            settled_at_ledger: None,
+            config,
+            ended: false,
+            player1_confirmed: false,
+            player2_confirmed: false,
+            breakdown: None,
+            last_action_ledger: env.ledger().sequence(),
+            decoy_seed,
+            oracle,
+            oracle_letters: None,
+            player1_salt: None,
+            player2_salt: None,
+            forfeited: 0,
+            vs_house: false,
+            rounds,
+            current_round: 1,
+            p1_round_wins: 0,
+            p2_round_wins: 0,
+            round_complete: false,
+            replays: 0,
         };
 
-        // Store game in temporary storage with 30-day TTL
+        // Store game in temporary storage with the configured default TTL
+        // (30 days unless the admin changed the policy)
         let game_key = DataKey::Game(session_id);
         env.storage().temporary().set(&game_key, &game);
+        env.storage().temporary().remove(&reservation_key);
+        Self::move_status(env, None, STATUS_WAITING);
+        Self::index_player_game(env, &game.player1, session_id);
+        Self::index_player_game(env, &game.player2, session_id);
+
+        // Remember the highest session id ever created so a missing game
+        // at or below it can be reported as expired rather than unknown
+        let max_session: Option<u32> = env.storage().instance().get(&DataKey::MaxSession);
+        let is_new_max = match max_session {
+            Some(max) => session_id > max,
+            None => true,
+        };
+        if is_new_max {
+            env.storage().instance().set(&DataKey::MaxSession, &session_id);
+        }
 
-        // Set TTL to ensure game is retained for at least 30 days
+        let (default_ttl, _) = Self::get_ttl_policy(env.clone());
         env.storage()
             .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+            .extend_ttl(&game_key, default_ttl, default_ttl);
 
         // Event emitted by GameHub contract (GameStarted)
 
         Ok(())
     }
 
+    /* -------------------------------------------- */
+    /* RESERVE SESSION                              */
+    /* -------------------------------------------- */
+    /// Allocate the next free session ID for `caller`, so clients don't
+    /// have to guess one that neither another client nor the hub is using.
+    /// IDs come from an incrementing counter, skipping any already taken by
+    /// a game started without a reservation.
+    ///
+    /// # Arguments
+    /// * `caller` - The player reserving the ID; must be one of the players
+    ///   when the game is started
+    ///
+    /// # Returns
+    /// * `u32` - The reserved session ID
+    pub fn reserve_session(env: Env, caller: Address) -> u32 {
+        caller.require_auth();
+
+        let mut session_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextSession)
+            .unwrap_or(0);
+        while env.storage().temporary().has(&DataKey::Game(session_id))
+            || env
+                .storage()
+                .temporary()
+                .has(&DataKey::ReservedSession(session_id))
+        {
+            session_id += 1;
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::NextSession, &(session_id + 1));
+
+        let key = DataKey::ReservedSession(session_id);
+        env.storage().temporary().set(&key, &caller);
+        let (default_ttl, _) = Self::get_ttl_policy(env.clone());
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, default_ttl, default_ttl);
+
+        session_id
+    }
+
     /* -------------------------------------------- */
     /* GET GAME                                     */
     /* -------------------------------------------- */
@@ -196,64 +940,597 @@ impl AlphaDuelContract {
             .get(&key)
             .ok_or(Error::GameNotFound)
     }
-    /* -------------------------------------------- */
-    /* MAKE GUESS (3 LETTERS)                       */
-    /* -------------------------------------------- */
-    pub fn make_guess(env: Env, session_id: u32, player: Address, guess: Vec<u32>) -> Result<(), Error> {
-    player.require_auth();
 
-    let key = DataKey::Game(session_id);
-    let mut game: Game = env
-        .storage()
-        .temporary()
-        .get(&key)
-        .ok_or(Error::GameNotFound)?; // ✅ Correct
+    /// Get what a game is waiting on, derived from its guesses, commitments
+    /// and result.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `GameStatus` - The game's current stage
+    pub fn get_game_status(env: Env, session_id: u32) -> GameStatus {
+        let Ok(game) = Self::get_game(env, session_id) else {
+            return GameStatus::NotFound;
+        };
 
-    if game.winner.is_some() {
-        panic_with_error!(env, Error::GameAlreadyEnded);
+        let moved = |guess: &Option<Vec<u32>>, commitment: &Option<BytesN<32>>| {
+            guess.is_some() || commitment.is_some()
+        };
+        // The house's guess is drawn when the game is settled
+        let house_ready = game.vs_house && game.player1_guess.is_some();
+        if game.result.is_some() {
+            GameStatus::Settled
+        } else if house_ready || (game.player1_guess.is_some() && game.player2_guess.is_some()) {
+            GameStatus::ReadyToSettle
+        } else if moved(&game.player1_guess, &game.player1_guess_commitment)
+            && moved(&game.player2_guess, &game.player2_guess_commitment)
+        {
+            GameStatus::WaitingForReveals
+        } else {
+            GameStatus::WaitingForCommits
+        }
     }
 
-    if player == game.player1 {
-        if game.player1_guess.is_some() {
-            panic_with_error!(env, Error::AlreadyGuessed);
-        }
-        game.player1_guess = Some(guess);
-    } else if player == game.player2 {
-        if game.player2_guess.is_some() {
-            panic_with_error!(env, Error::AlreadyGuessed);
+    /// Get the session IDs of the games a player is in.
+    ///
+    /// Ids are added when a game starts and removed when it is ended or
+    /// cancelled. Games live in temporary storage and can expire without
+    /// ever being ended; their ids are left out here and pruned from the
+    /// index the next time the player starts a game.
+    ///
+    /// # Arguments
+    /// * `player` - The player's address
+    ///
+    /// # Returns
+    /// * `Vec<u32>` - Session IDs, oldest first
+    pub fn get_player_games(env: Env, player: Address) -> Vec<u32> {
+        let ids: Vec<u32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PlayerGames(player))
+            .unwrap_or(Vec::new(&env));
+
+        let mut live = Vec::new(&env);
+        for id in ids.iter() {
+            if env.storage().temporary().has(&DataKey::Game(id)) {
+                live.push_back(id);
+            }
         }
-        game.player2_guess = Some(guess);
-    } else {
-        panic_with_error!(env, Error::NotPlayer);
+        live
     }
 
-    env.storage()
-        .temporary()
-        .set(&key, &game);
+    /// Get the most recently settled session IDs, for an activity feed.
+    /// Only the last 20 settlements are kept.
+    ///
+    /// # Arguments
+    /// * `limit` - Maximum number of IDs to return
+    ///
+    /// # Returns
+    /// * `Vec<u32>` - Session IDs, most recent first
+    pub fn recent_settled(env: Env, limit: u32) -> Vec<u32> {
+        let recent: Vec<u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::RecentSettled)
+            .unwrap_or(Vec::new(&env));
 
-    Ok(())
-}
+        let mut out = Vec::new(&env);
+        for session_id in recent.iter().rev().take(limit as usize) {
+            out.push_back(session_id);
+        }
+        out
+    }
 
-/* -------------------------------------------- */
-    /* COMMIT GUESS TO CONTRACT                */
+    /// Get a game in its XDR-serialized form, for off-chain caching.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Bytes` - XDR encoding of the `Game` (an `ScVal` map)
+    pub fn get_game_raw(env: Env, session_id: u32) -> Result<Bytes, Error> {
+        let game = Self::get_game(env.clone(), session_id)?;
+        Ok(game.to_xdr(&env))
+    }
+
+    /// Get the ledger at which a game was settled.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Option<u32>` - Settlement ledger sequence, `None` while unsettled
+    pub fn get_settled_at(env: Env, session_id: u32) -> Result<Option<u32>, Error> {
+        let game = Self::get_game(env, session_id)?;
+        Ok(game.settled_at_ledger)
+    }
+
+    /// Get how far a game has progressed, for progress bars.
+    /// A player has acted once they have guessed or committed.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `u32` - 0 (created), 33 (one acted), 66 (both acted) or 100 (settled)
+    pub fn get_progress(env: Env, session_id: u32) -> Result<u32, Error> {
+        let game = Self::get_game(env, session_id)?;
+
+        if game.result.is_some() {
+            return Ok(100);
+        }
+
+        let p1_acted = game.player1_guess.is_some() || game.player1_guess_commitment.is_some();
+        let p2_acted = game.player2_guess.is_some() || game.player2_guess_commitment.is_some();
+
+        Ok(match (p1_acted, p2_acted) {
+            (true, true) => 66,
+            (false, false) => 0,
+            _ => 33,
+        })
+    }
+
+    /// Get how many ledgers have passed since anything happened in a game,
+    /// e.g. to show how long a player has been waiting for their opponent.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `u32` - Ledgers since the last mutating call on the game
+    pub fn ledgers_since_last_action(env: Env, session_id: u32) -> Result<u32, Error> {
+        let game = Self::get_game(env.clone(), session_id)?;
+        Ok(env.ledger().sequence().saturating_sub(game.last_action_ledger))
+    }
+
+    /// Get the component scores of a settled game, for result screens.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `ResultBreakdown` - Exact/present counts per player, winner and
+    ///   reason, or `Error::GameNotSettled` before settlement
+    pub fn get_result_breakdown(env: Env, session_id: u32) -> Result<ResultBreakdown, Error> {
+        let game = Self::get_game(env, session_id)?;
+        game.breakdown.ok_or(Error::GameNotSettled)
+    }
+
+    /* -------------------------------------------- */
+    /* GUESS OVERLAP (UI HINT)                      */
+    /* -------------------------------------------- */
+    /// Count the distinct letters both players' guesses have in common.
+    /// Compares the guesses with each other only, so it reveals nothing
+    /// about the hidden word.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `u32` - Number of shared letters (requires both guesses),
+    ///   or `Error::HintsDisabled` if the game was started without hints
+    pub fn guess_overlap(env: Env, session_id: u32) -> Result<u32, Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        if !game.config.hints_enabled {
+            return Err(Error::HintsDisabled);
+        }
+
+        let p1_guess = game.player1_guess.ok_or(Error::BothPlayersNotGuessed)?;
+        let p2_guess = game.player2_guess.ok_or(Error::BothPlayersNotGuessed)?;
+
+        let mut seen: Vec<u32> = Vec::new(&env);
+        let mut shared = 0;
+        for letter in p1_guess.iter() {
+            if !seen.contains(letter) && p2_guess.contains(letter) {
+                shared += 1;
+            }
+            seen.push_back(letter);
+        }
+
+        Ok(shared)
+    }
+
+    /// Preview the final points if the game were settled now, without
+    /// changing anything. The winner takes all; a draw leaves both stakes.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `(i128, i128)` - `(player1_final, player2_final)` (requires both
+    ///   guesses), or `Error::HintsDisabled` in a game without hints
+    pub fn preview_settlement(env: Env, session_id: u32) -> Result<(i128, i128), Error> {
+        let game = Self::get_game(env.clone(), session_id)?;
+        if !game.config.hints_enabled && game.result.is_none() {
+            return Err(Error::HintsDisabled);
+        }
+        let (p1_correct, p2_correct) = Self::current_scores(&env, &game)?;
+
+        let pot = game.player1_points + game.player2_points;
+        Ok(match Self::decide(&game.config, p1_correct, p2_correct) {
+            GameResult::Player1Win => (pot, 0),
+            GameResult::Player2Win => (0, pot),
+            GameResult::Draw => (game.player1_points, game.player2_points),
+        })
+    }
+
+    /// Get what a player would receive by winning, from the current
+    /// stakes. The winner takes the whole pot; there is no fee or partial
+    /// take, since the Game Hub pays out the full pot.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - One of the players
+    ///
+    /// # Returns
+    /// * `i128` - The player's points after a win
+    pub fn expected_payout(env: Env, session_id: u32, player: Address) -> Result<i128, Error> {
+        let game = Self::get_game(env, session_id)?;
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+
+        Ok(game.player1_points + game.player2_points)
+    }
+
+    /// Get how many points the loser forfeited to the winner, for result
+    /// displays.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `i128` - Points moved to the winner (0 on a draw); fails with
+    ///   `GameNotSettled` before settlement
+    pub fn forfeited_amount(env: Env, session_id: u32) -> Result<i128, Error> {
+        let game = Self::get_game(env, session_id)?;
+        if game.result.is_none() {
+            return Err(Error::GameNotSettled);
+        }
+        Ok(game.forfeited)
+    }
+
+    /// Get the highest match count a guess can achieve in this game,
+    /// without revealing the word.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `u32` - Distinct hidden letters, capped at the guess length
+    pub fn max_possible_score(env: Env, session_id: u32) -> Result<u32, Error> {
+        let game = Self::get_game(env.clone(), session_id)?;
+        let hidden = Self::game_hidden_letters(&env, &game)?;
+
+        Ok(Self::distinct_letters(&hidden).min(game.guess_length))
+    }
+
+    /// Get how a game scores guesses, so clients can explain results
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `ScoringMode` - `Loose` (letters anywhere) or `Exact` (positional)
+    pub fn get_scoring_mode(env: Env, session_id: u32) -> Result<ScoringMode, Error> {
+        Ok(Self::get_game(env, session_id)?.config.scoring_mode)
+    }
+
+    /// Get how many letters each guess must have, so the frontend knows
+    /// how many inputs to render.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `u32` - The game's guess length
+    pub fn get_guess_length(env: Env, session_id: u32) -> Result<u32, Error> {
+        Ok(Self::get_game(env, session_id)?.guess_length)
+    }
+
+    /// Get the margin between the players' scores, for result displays
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `i32` - `p1_correct - p2_correct` (requires both guesses), or
+    ///   `Error::HintsDisabled` before settlement in a game without hints
+    pub fn score_margin(env: Env, session_id: u32) -> Result<i32, Error> {
+        let game = Self::get_game(env.clone(), session_id)?;
+        if !game.config.hints_enabled && game.result.is_none() {
+            return Err(Error::HintsDisabled);
+        }
+        let (p1_correct, p2_correct) = Self::current_scores(&env, &game)?;
+        Ok(p1_correct as i32 - p2_correct as i32)
+    }
+
+    /// Check whether both players submitted exactly the same guess
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the guesses are identical (requires both guesses)
+    pub fn guesses_identical(env: Env, session_id: u32) -> Result<bool, Error> {
+        let game = Self::get_game(env, session_id)?;
+
+        let p1_guess = game.player1_guess.ok_or(Error::BothPlayersNotGuessed)?;
+        let p2_guess = game.player2_guess.ok_or(Error::BothPlayersNotGuessed)?;
+
+        Ok(p1_guess == p2_guess)
+    }
+
+    /* -------------------------------------------- */
+    /* MAKE GUESS                                   */
+    /* -------------------------------------------- */
+    /// Submit a plaintext guess. Players who committed must use
+    /// `reveal_guess` instead (`CommitmentRequired`).
+    pub fn make_guess(env: Env, session_id: u32, player: Address, guess: Vec<u32>) -> Result<(), Error> {
+        Self::record_guess(env, session_id, player, guess, None)
+    }
+
+    /* -------------------------------------------- */
+    /* REVEAL COMMITTED GUESS                       */
+    /* -------------------------------------------- */
+    /// Reveal a committed guess together with the salt it was committed
+    /// with. The commitment is recomputed from the guess and salt (see
+    /// `debug_commitment_preimage`) and must match before the guess is stored;
+    /// `reveal_winner` checks it again before scoring.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The revealing player (or their delegate)
+    /// * `guess` - The committed letters (A=0..Z=25)
+    /// * `salt` - The 32-byte salt used in the commitment
+    pub fn reveal_guess(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        guess: Vec<u32>,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        Self::record_guess(env, session_id, player, guess, Some(salt))
+    }
+
+    /// Check whether `reveal_guess` would accept a guess and salt, without
+    /// changing anything.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The committed player
+    /// * `guess` - The letters to reveal (A=0..Z=25)
+    /// * `salt` - The 32-byte salt used in the commitment
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the guess is valid and matches the commitment
+    pub fn can_reveal(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        guess: Vec<u32>,
+        salt: BytesN<32>,
+    ) -> Result<bool, Error> {
+        let game = Self::get_game(env.clone(), session_id)?;
+
+        let commitment = if player == game.player1 {
+            &game.player1_guess_commitment
+        } else if player == game.player2 {
+            &game.player2_guess_commitment
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        Ok(Self::validate_guess(&game, &guess).is_ok()
+            && Self::verify_reveal(&env, session_id, &player, commitment, &guess, &salt).is_ok())
+    }
+
+    fn record_guess(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        guess: Vec<u32>,
+        salt: Option<BytesN<32>>,
+    ) -> Result<(), Error> {
+    Self::require_not_paused(&env)?;
+
+    let key = DataKey::Game(session_id);
+    let mut game: Game = env
+        .storage()
+        .temporary()
+        .get(&key)
+        .ok_or_else(|| Self::missing_game_error(&env, session_id))?;
+
+    // `player` may be a delegated session key acting for one of the players
+    let player = Self::authorize_player(&env, &game, player)?;
+
+    if game.result.is_some() {
+        panic_with_error!(env, Error::GameAlreadyEnded);
+    }
+    if game.round_complete {
+        return Err(Error::RoundAlreadyComplete);
+    }
+    // The oracle's word is fixed before anyone plays on it
+    if game.oracle.is_some() && game.oracle_letters.is_none() {
+        return Err(Error::WordNotReported);
+    }
+
+    Self::validate_guess(&game, &guess)?;
+    let status_before = Self::status_of(&game);
+
+    // In a commit/reveal game, a simultaneous reveal waits for both commitments
+    let committed = (
+        game.player1_guess_commitment.is_some(),
+        game.player2_guess_commitment.is_some(),
+    );
+    if game.config.reveal_order == RevealOrder::Simultaneous
+        && committed != (false, false)
+        && committed != (true, true)
+    {
+        panic_with_error!(env, Error::BothPlayersNotCommitted);
+    }
+
+    // A committed player must reveal with their salt; plaintext guesses
+    // are only for games played without commitments
+    let own_commitment = if player == game.player1 {
+        &game.player1_guess_commitment
+    } else if player == game.player2 {
+        &game.player2_guess_commitment
+    } else {
+        &None
+    };
+    if salt.is_none() && own_commitment.is_some() {
+        return Err(Error::CommitmentRequired);
+    }
+    let plaintext = salt.is_none()
+        && game.player1_guess_commitment.is_none()
+        && game.player2_guess_commitment.is_none();
+
+    if player == game.player1 {
+        if game.player1_guess.is_some() {
+            panic_with_error!(env, Error::AlreadyGuessed);
+        }
+        if let Some(salt) = &salt {
+            Self::verify_reveal(&env, session_id, &player, &game.player1_guess_commitment, &guess, salt)?;
+        }
+        game.player1_guess = Some(guess.clone());
+        game.player1_salt = salt;
+    } else if player == game.player2 {
+        if game.player2_guess.is_some() {
+            panic_with_error!(env, Error::AlreadyGuessed);
+        }
+        if let Some(salt) = &salt {
+            Self::verify_reveal(&env, session_id, &player, &game.player2_guess_commitment, &guess, salt)?;
+        }
+        game.player2_guess = Some(guess.clone());
+        game.player2_salt = salt;
+    } else {
+        panic_with_error!(env, Error::NotPlayer);
+    }
+
+    // A perfect exact match settles the game for this player right away
+    // (only once the word is known, i.e. not while awaiting an oracle).
+    // Plaintext games only: committed guesses settle together on reveal.
+    if game.config.early_settle_on_perfect
+        && plaintext
+        && Self::game_word(&env, &game).ok() == Some(guess.clone())
+    {
+        let exact = guess.len();
+        let (counts, result) = if player == game.player1 {
+            ((exact, 0, 0, 0), GameResult::Player1Win)
+        } else {
+            ((0, 0, exact, 0), GameResult::Player2Win)
+        };
+        Self::close_round(
+            &env,
+            session_id,
+            &mut game,
+            status_before,
+            result,
+            counts,
+            ResultReason::PerfectMatch,
+        );
+    } else {
+        Self::save_game(&env, &key, &mut game);
+        Self::move_status(&env, Some(status_before), Self::status_of(&game));
+    }
+    if Self::emits(&env, EVENT_LEVEL_VERBOSE) {
+        GuessMade { session_id, player }.publish(&env);
+    }
+
+    Ok(())
+}
+
+    /* -------------------------------------------- */
+    /* MAKE GUESS FROM A WORD STRING                */
+    /* -------------------------------------------- */
+    /// Submit a guess as an uppercase word (e.g. "APPLE") instead of letter
+    /// indices. The word is encoded A=0..Z=25 and stored like `make_guess`.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The guessing player (or their delegate)
+    /// * `word` - The guess; only `A`-`Z` are accepted
+    pub fn make_guess_str(env: Env, session_id: u32, player: Address, word: String) -> Result<(), Error> {
+        // Length is reported apart from bad characters
+        let len = word.len() as usize;
+        if len == 0 || len > MAX_GUESS_STR_LEN {
+            return Err(Error::InvalidGuessLength);
+        }
+
+        let guess = Self::string_letters(&env, &word).ok_or(Error::InvalidGuessValue)?;
+        Self::make_guess(env, session_id, player, guess)
+    }
+
+/* -------------------------------------------- */
+    /* COMMIT GUESS TO CONTRACT                */
     /* -------------------------------------------- */
+    /// Commit to a guess without revealing it.
+    ///
+    /// `guess_commitment` must be `sha256(salt || session_id || player || letters)`
+    /// as laid out by `debug_commitment_preimage`, with a fresh random 32-byte
+    /// salt. The salt keeps the small guess space from being brute-forced
+    /// and is only disclosed in `reveal_guess`.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The committing player (or their delegate)
+    /// * `guess_commitment` - The 32-byte commitment hash
 pub fn commit_guess(
     env: Env,
     session_id: u32,
     player: Address,
     guess_commitment: BytesN<32>,
 ) -> Result<(), Error> {
-    player.require_auth();
+    Self::require_not_paused(&env)?;
 
     let key = DataKey::Game(session_id);
     let mut game: Game = env.storage().temporary()
         .get(&key)
-        .ok_or(Error::GameNotFound)?;
+        .ok_or_else(|| Self::missing_game_error(&env, session_id))?;
+
+    // `player` may be a delegated session key acting for one of the players
+    let player = Self::authorize_player(&env, &game, player)?;
 
-         // Ensure game is active
-    if game.winner.is_some() {
+         // Ensure game is active: neither decided nor reported to the hub
+    if game.result.is_some() || game.ended {
         return Err(Error::GameAlreadyEnded);
     }
+    if game.round_complete {
+        return Err(Error::RoundAlreadyComplete);
+    }
+    if game.oracle.is_some() && game.oracle_letters.is_none() {
+        return Err(Error::WordNotReported);
+    }
+    // The house guesses in plaintext at settlement and never commits, so a
+    // committed player could never reveal
+    if game.vs_house {
+        return Err(Error::ProtocolMixing);
+    }
+
+    // Once the opponent has guessed in plaintext, committing would mean
+    // hiding a guess made after seeing theirs; a player who has already
+    // guessed in plaintext has nothing left to commit
+    let (own_plaintext, opponent_plaintext) = if player == game.player1 {
+        (
+            game.player1_guess.is_some() && game.player1_guess_commitment.is_none(),
+            game.player2_guess.is_some() && game.player2_guess_commitment.is_none(),
+        )
+    } else {
+        (
+            game.player2_guess.is_some() && game.player2_guess_commitment.is_none(),
+            game.player1_guess.is_some() && game.player1_guess_commitment.is_none(),
+        )
+    };
+    if own_plaintext || opponent_plaintext {
+        return Err(Error::ProtocolMixing);
+    }
 
     // Player1 commits
     if player == game.player1 {
@@ -272,279 +1549,2262 @@ pub fn commit_guess(
         return Err(Error::NotPlayer);
     }
 
-    env.storage().temporary().set(&key, &game);
+    Self::save_game(&env, &key, &mut game);
+    if Self::emits(&env, EVENT_LEVEL_VERBOSE) {
+        GuessCommitted { session_id, player }.publish(&env);
+    }
     Ok(())
 }
 
+    /// Check whether a player has committed a guess in the round in play.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of one of the players
+    ///
+    /// # Returns
+    /// * `bool` - `true` once the player's commitment is set
+    pub fn has_committed(env: Env, session_id: u32, player: Address) -> Result<bool, Error> {
+        let game = Self::get_game(env, session_id)?;
+
+        if player == game.player1 {
+            Ok(game.player1_guess_commitment.is_some())
+        } else if player == game.player2 {
+            Ok(game.player2_guess_commitment.is_some())
+        } else {
+            Err(Error::NotPlayer)
+        }
+    }
+
+    /// Get the exact bytes a guess commitment is built from
+    ///
+    /// The commitment passed to `commit_guess` is `sha256` of these bytes.
+    /// Off-chain clients (JS, Noir) can use this to check their own encoding
+    /// byte-for-byte before committing.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the committing player
+    /// * `guess` - The guessed letters (A=0..Z=25)
+    /// * `salt` - 32 random bytes kept secret until reveal
+    ///
+    /// # Returns
+    /// * `Bytes` - The commitment preimage
+    pub fn debug_commitment_preimage(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        guess: Vec<u32>,
+        salt: BytesN<32>,
+    ) -> Bytes {
+        Self::commitment_preimage(&env, session_id, &player, &guess, &salt)
+    }
+
     /* -------------------------------------------- */
     /* REVEAL WINNER + REPORT TO HUB                */
     /* -------------------------------------------- */
-    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
-    let key = DataKey::Game(session_id);
-    let mut game: Game = env
-        .storage()
-        .temporary()
-        .get(&key)
-        .ok_or(Error::GameNotFound)?;
-
-    if game.player1_guess.is_none() || game.player2_guess.is_none() {
-        panic_with_error!(env, Error::BothPlayersNotGuessed);
+    /// Settle the game (see `settle`) and return the winner.
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The winner, or `None` if the game is a draw
+    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
+        Ok(Self::reveal_winner_detailed(env, session_id)?.winner)
     }
 
-    // 1️⃣ Get hidden word letters (0..25)
-    let hidden = Self::get_hidden_letters(env.clone(), game.hidden_word_id);
-
-    // 2️⃣ Unwrap guesses
-    let p1_guess = game.player1_guess.clone().unwrap();
-    let p2_guess = game.player2_guess.clone().unwrap();
-
-    // 3️⃣ Count correct letters using "loose match" logic
-    fn count_matches(hidden: &Vec<u32>, guess: &Vec<u32>) -> u32 {
-        let mut count = 0;
-        for g in guess.iter() {
-            if hidden.contains(g) {
-                count += 1;
+    /// Settle like `reveal_winner`, also reporting how many letters each
+    /// player got right.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `RevealOutcome` - Winner, per-player correct letters and draw flag
+    pub fn reveal_winner_detailed(env: Env, session_id: u32) -> Result<RevealOutcome, Error> {
+        Self::settle(env.clone(), session_id)?;
+        let game = Self::get_game(env.clone(), session_id)?;
+
+        let hidden = Self::game_hidden_letters(&env, &game)?;
+        let correct = |guess: &Option<Vec<u32>>| {
+            let (exact, present) = Self::match_counts(&hidden, guess.as_ref().unwrap());
+            match game.config.scoring_mode {
+                ScoringMode::Loose => exact + present,
+                ScoringMode::Exact => exact,
             }
-        }
-        count
-    }
-
-    let p1_correct = count_matches(&hidden, &p1_guess);
-    let p2_correct = count_matches(&hidden, &p2_guess);
-
-    let winner = if p1_correct >= p2_correct {
-            game.player1.clone()
-        } else {
-            game.player2.clone()
         };
 
+        Ok(RevealOutcome {
+            winner: game.winner.clone(),
+            player1_correct: correct(&game.player1_guess),
+            player2_correct: correct(&game.player2_guess),
+            is_draw: game.result == Some(GameResult::Draw),
+        })
+    }
 
-    // 5️⃣ Save winner to game
-    game.winner = Some(winner.clone());
-    env.storage().temporary().set(&key, &game);
+    /* -------------------------------------------- */
+    /* NEXT ROUND                                   */
+    /* -------------------------------------------- */
+    /// Start the next round of a best-of-N match once the current one has
+    /// been scored by `reveal_winner`. Guesses are cleared and a fresh
+    /// hidden word is drawn; stakes stay locked until the match is settled.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the match
+    ///
+    /// # Returns
+    /// * `u32` - The round now being played
+    pub fn next_round(env: Env, session_id: u32) -> Result<u32, Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.result.is_some() {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if !game.round_complete {
+            return Err(Error::BothPlayersNotGuessed);
+        }
+
+        let status_before = Self::status_of(&game);
+        Self::seed_prng(
+            &env,
+            session_id,
+            &game.player1,
+            &game.player2,
+            game.current_round + 1,
+            game.replays,
+            SEED_TAG_WORD,
+        );
+        let hidden_word_id = Self::select_word_id(&env, game.config.guess_length)?;
+        game.guess_length = Self::guess_length_for(&env, &game.config, hidden_word_id);
+        game.hidden_word_id = hidden_word_id;
+        game.hidden_letters = Self::get_hidden_letters(env.clone(), hidden_word_id);
+        game.oracle_letters = None;
+
+        game.player1_guess = None;
+        game.player2_guess = None;
+        game.player1_guess_commitment = None;
+        game.player2_guess_commitment = None;
+        game.player1_salt = None;
+        game.player2_salt = None;
+        game.current_round += 1;
+        game.round_complete = false;
+
+        Self::save_game(&env, &key, &mut game);
+        Self::move_status(&env, Some(status_before), STATUS_WAITING);
+
+        Ok(game.current_round)
+    }
+
+    /* -------------------------------------------- */
+    /* SETTLE                                       */
+    /* -------------------------------------------- */
+    /// Score both revealed guesses and settle the game.
+    ///
+    /// The winner takes the loser's points. Equal scores are a draw and
+    /// each player keeps their own stake.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `GameResult` - The outcome, also stored on the game
+    pub fn settle(env: Env, session_id: u32) -> Result<GameResult, Error> {
+    Self::require_not_paused(&env)?;
+
+    let key = DataKey::Game(session_id);
+    let mut game: Game = env
+        .storage()
+        .temporary()
+        .get(&key)
+        .ok_or(Error::GameNotFound)?;
+
+    // A settled game is never scored (or paid) twice
+    if game.result.is_some() {
+        return Err(Error::GameAlreadyEnded);
+    }
+    if game.round_complete {
+        return Err(Error::RoundAlreadyComplete);
+    }
+    let status_before = Self::status_of(&game);
+
+    // The house picks its letters only once the player's guess is in
+    if game.vs_house && game.player1_guess.is_some() && game.player2_guess.is_none() {
+        game.player2_guess = Some(Self::house_guess(&env, session_id, &game));
+    }
+    if game.player1_guess.is_none() || game.player2_guess.is_none() {
+        panic_with_error!(env, Error::BothPlayersNotGuessed);
+    }
+
+    // Revealed guesses must match what was committed
+    Self::verify_commitments(&env, session_id, &game)?;
+
+    // 1️⃣ Get hidden word letters (0..25)
+    let hidden = Self::game_hidden_letters(&env, &game)?;
+
+    // 2️⃣ Unwrap guesses
+    let p1_guess = game.player1_guess.clone().unwrap();
+    let p2_guess = game.player2_guess.clone().unwrap();
+
+    // 3️⃣ Score letters: exact-position and present-elsewhere matches are
+    // weighted separately (both 1 by default, i.e. "loose match")
+    let weights = Self::get_score_weights(env.clone());
+    let p1_correct = Self::score_guess(&hidden, &p1_guess, weights, game.config.scoring_mode);
+    let p2_correct = Self::score_guess(&hidden, &p2_guess, weights, game.config.scoring_mode);
+
+    let round_result = Self::decide(&game.config, p1_correct, p2_correct);
+
+    // 4️⃣ Keep the component scores for result screens
+    let (p1_exact, p1_present) = Self::match_counts(&hidden, &p1_guess);
+    let (p2_exact, p2_present) = Self::match_counts(&hidden, &p2_guess);
+
+    // 5️⃣ Tally the round and, once the game is decided, pay the winner
+    Ok(Self::close_round(
+        &env,
+        session_id,
+        &mut game,
+        status_before,
+        round_result,
+        (p1_exact, p1_present, p2_exact, p2_present),
+        ResultReason::Score,
+    ))
+}
+
+    /// Record the outcome of a round. Single-round games (and the deciding
+    /// round of a best-of-N match) are settled: the winner takes the pot
+    /// and stats and the `WinnerRevealed` event follow.
+    /// Otherwise the round is marked complete for `next_round`.
+    ///
+    /// `counts` are `(p1_exact, p1_present, p2_exact, p2_present)` for the
+    /// breakdown, and `reason` explains a decisive result (a draw is always
+    /// `ResultReason::Draw`). Returns the round's result for a round that
+    /// doesn't decide the match, the final result otherwise.
+    fn close_round(
+        env: &Env,
+        session_id: u32,
+        game: &mut Game,
+        status_before: u32,
+        round_result: GameResult,
+        counts: (u32, u32, u32, u32),
+        reason: ResultReason,
+    ) -> GameResult {
+        let key = DataKey::Game(session_id);
+        Self::record_word_stats(env, game, round_result);
+
+        // Best-of-N: tally the round, and only settle once a player holds a
+        // majority of the rounds or every round has been played
+        let result = if game.rounds > 1 {
+            match round_result {
+                GameResult::Player1Win => game.p1_round_wins += 1,
+                GameResult::Player2Win => game.p2_round_wins += 1,
+                GameResult::Draw => {}
+            }
+
+            let needed = game.rounds.div_ceil(2);
+            if game.p1_round_wins < needed
+                && game.p2_round_wins < needed
+                && game.current_round < game.rounds
+            {
+                game.round_complete = true;
+                Self::save_game(env, &key, game);
+                Self::move_status(env, Some(status_before), Self::status_of(game));
+                return round_result;
+            }
+
+            match game.p1_round_wins.cmp(&game.p2_round_wins) {
+                core::cmp::Ordering::Greater => GameResult::Player1Win,
+                core::cmp::Ordering::Less => GameResult::Player2Win,
+                core::cmp::Ordering::Equal => GameResult::Draw,
+            }
+        } else {
+            round_result
+        };
+        let winner = match result {
+            GameResult::Player1Win => Some(game.player1.clone()),
+            GameResult::Player2Win => Some(game.player2.clone()),
+            GameResult::Draw => None,
+        };
+
+        let (p1_exact, p1_present, p2_exact, p2_present) = counts;
+        game.breakdown = Some(ResultBreakdown {
+            p1_exact,
+            p1_present,
+            p2_exact,
+            p2_present,
+            winner: winner.clone(),
+            reason: if result == GameResult::Draw {
+                ResultReason::Draw
+            } else {
+                reason
+            },
+        });
+
+        // Record player results against the stakes before they move
+        Self::record_player_stats(env, game, result);
+
+        // Winner takes the loser's points; on a draw both stakes stay where
+        // they are
+        Self::pay_winner(game, result);
+
+        // The house's points, won or refunded, go back to the bankroll
+        if game.vs_house {
+            let bankroll = Self::get_house_bankroll(env.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::HouseBankroll, &(bankroll + game.player2_points));
+        }
+
+        game.winner = winner.clone();
+        game.result = Some(result);
+        game.settled_at_ledger = Some(env.ledger().sequence());
+        Self::save_game(env, &key, game);
+        Self::move_status(env, Some(status_before), STATUS_SETTLED);
+        Self::record_recent_settled(env, session_id);
+
+        if Self::emits(env, EVENT_LEVEL_LIFECYCLE) {
+            WinnerRevealed { session_id, winner }.publish(env);
+        }
+
+        result
+    }
+
+    /* -------------------------------------------- */
+    /* WINNER FLAG MEANING                          */
+    /* -------------------------------------------- */
+    /// Which player a proof's `winner_flag` (the first public input to
+    /// `reveal_winner_with_proof`) declares the winner of a game.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `flag` - `1` for player1, `2` for player2
+    ///
+    /// # Returns
+    /// * `Address` - The player the flag maps to
+    pub fn winner_flag_meaning(env: Env, session_id: u32, flag: u32) -> Result<Address, Error> {
+        let game = Self::get_game(env, session_id)?;
+        Self::player_for_flag(&game, flag)
+    }
+
+    // The circuit's winner_flag convention: 1 = player1, 2 = player2
+    fn player_for_flag(game: &Game, flag: u32) -> Result<Address, Error> {
+        match flag {
+            1 => Ok(game.player1.clone()),
+            2 => Ok(game.player2.clone()),
+            _ => Err(Error::InvalidWinnerFlag),
+        }
+    }
+
+  /* -------------------------------------------- */
+    /* REVEAL WINNER WITH PROOF                     */
+    /* -------------------------------------------- */
+    pub fn reveal_winner_with_proof(
+    env: Env,
+    session_id: u32,
+    proof: Bytes,
+    public_inputs: Vec<u32>, // winner flag, then proof_binding_inputs
+) -> Result<Address, Error> {
+    Self::require_not_paused(&env)?;
+
+    // Load game
+    let mut game: Game = env
+        .storage()
+        .temporary()
+        .get(&DataKey::Game(session_id))
+        .ok_or(Error::GameNotFound)?;
+
+    // ✅ Prevent double settlement, and a second proof for the same round
+    if game.result.is_some() {
+        return Err(Error::GameAlreadyEnded);
+    }
+    if game.round_complete {
+        return Err(Error::RoundAlreadyComplete);
+    }
+
+    // Both players must have committed
+    if game.player1_guess_commitment.is_none() || game.player2_guess_commitment.is_none() {
+        panic_with_error!(env, Error::BothPlayersNotCommitted);
+    }
+    let status_before = Self::status_of(&game);
+
+    // ---------------------------------------------------
+    // ✅ Step 1: Verify proof with the verifier contract
+    // ---------------------------------------------------
+    if proof.len() == 0 {
+        return Err(Error::InvalidProof);
+    }
+
+    // Reject replays of a proof that already settled a game
+    let proof_hash: BytesN<32> = env.crypto().sha256(&proof).into();
+    let proof_key = DataKey::UsedProof(proof_hash);
+    if env.storage().temporary().has(&proof_key) {
+        return Err(Error::ProofAlreadyUsed);
+    }
+
+    let verifier_addr: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Verifier)
+        .expect("Verifier address not set");
+    let verifier = VerifierClient::new(&env, &verifier_addr);
+    if !verifier.verify(&proof, &public_inputs) {
+        panic_with_error!(env, Error::InvalidProof);
+    }
+
+    // ---------------------------------------------------
+    // ✅ Step 2: Extract winner from Noir public output
+    // ---------------------------------------------------
+    //
+    // Noir circuit outputs:
+    // winner_flag = 1 → player1 wins
+    // winner_flag = 2 → player2 wins
+    //
+    if public_inputs.len() < 1 {
+        return Err(Error::ProofInputMismatch);
+    }
+
+    // The rest of the public inputs bind the proof to this game's word and
+    // commitments (see `proof_binding_inputs`)
+    if public_inputs.slice(1..public_inputs.len()) != Self::proof_binding_inputs(&env, &game) {
+        return Err(Error::ProofInputMismatch);
+    }
+
+    let winner_flag = public_inputs.get(0).unwrap();
+    let winner = Self::player_for_flag(&game, winner_flag)?;
+
+    // ---------------------------------------------------
+    // ✅ Step 3: Save winner on-chain
+    // ---------------------------------------------------
+    //
+    // The proof decides the round; best-of-N games settle through the
+    // same round tally as scored games
+    let round_result = if winner == game.player1 {
+        GameResult::Player1Win
+    } else {
+        GameResult::Player2Win
+    };
+    Self::close_round(
+        &env,
+        session_id,
+        &mut game,
+        status_before,
+        round_result,
+        (0, 0, 0, 0),
+        ResultReason::Proof,
+    );
+
+    // Remember the proof for as long as games live, mapped to the session it settled
+    let (default_ttl, _) = Self::get_ttl_policy(env.clone());
+    env.storage().temporary().set(&proof_key, &session_id);
+    env.storage()
+        .temporary()
+        .extend_ttl(&proof_key, default_ttl, default_ttl);
+
+    Ok(winner)
+}
+
+    /// Check whether a proof has already been used to settle a game.
+    ///
+    /// # Arguments
+    /// * `proof_hash` - sha256 of the proof bytes
+    pub fn is_proof_used(env: Env, proof_hash: BytesN<32>) -> bool {
+        env.storage()
+            .temporary()
+            .has(&DataKey::UsedProof(proof_hash))
+    }
+
+
+ //  /* -------------------------------------------- */
+    /* END GAME AND REPORT TO HUB                   */
+    /* -------------------------------------------- */
+pub fn end_game(env: Env, session_id: u32, caller: Address) -> Result<(), Error> {
+
+    // Caller must sign
+    caller.require_auth();
+
+    let key = DataKey::Game(session_id);
+
+    let mut game: Game = env
+        .storage()
+        .temporary()
+        .get(&key)
+        .ok_or(Error::GameNotFound)?;
+
+    // Report to the hub only once, or it may credit the winner twice
+    if game.ended {
+        return Err(Error::GameAlreadyEnded);
+    }
+
+    // A best-of-N match is only reported once it has been decided
+    if game.rounds > 1 && game.result.is_none() {
+        return Err(Error::MatchNotOver);
+    }
+
+    // Any settled game can be reported, committed or plaintext
+    let result = game.result.ok_or(Error::GameNotSettled)?;
+
+    // The Game Hub has no draw outcome and `player1_won = false` would
+    // credit player2, so a draw is replayed (`replay_draw`) rather than
+    // reported
+    if result == GameResult::Draw {
+        return Err(Error::DrawNotReportable);
+    }
+
+    // Mutual confirmation: both players must accept the result. If one of
+    // them doesn't within the window, the admin can report it instead.
+    if game.config.require_confirmation && !(game.player1_confirmed && game.player2_confirmed) {
+        let admin = Self::get_admin(env.clone());
+        let deadline = game
+            .settled_at_ledger
+            .unwrap_or(0)
+            .saturating_add(CONFIRMATION_WINDOW_LEDGERS);
+        if caller != admin || env.ledger().sequence() < deadline {
+            return Err(Error::ResultNotConfirmed);
+        }
+    }
+
+    let game_hub_addr: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::GameHubAddress)
+        .expect("GameHub address not set");
+
+    let game_hub = GameHubClient::new(&env, &game_hub_addr);
+
+    let player1_won = result == GameResult::Player1Win;
+
+    game_hub.end_game(&session_id, &player1_won);
+    Self::unindex_game(&env, &game, session_id);
+
+    // Player profiles already hold the result, so the game itself can go
+    if Self::get_auto_purge_on_end(env.clone()) {
+        env.storage().temporary().remove(&key);
+        return Ok(());
+    }
+
+    game.ended = true;
+    Self::save_game(&env, &key, &mut game);
+
+    Ok(())
+}
+
+    /* -------------------------------------------- */
+    /* REPLAY DRAW                                  */
+    /* -------------------------------------------- */
+    /// Replay a drawn game under the same session.
+    ///
+    /// The Game Hub can't record a draw (see `end_game`), so the stakes stay
+    /// locked there while the players start over from round 1 on a fresh
+    /// word. The decisive result is then reported with `end_game` as usual.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the drawn game
+    /// * `player` - Either player
+    pub fn replay_draw(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or_else(|| Self::missing_game_error(&env, session_id))?;
+
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        match game.result {
+            None => return Err(Error::GameNotSettled),
+            Some(GameResult::Draw) => {}
+            Some(_) => return Err(Error::NotADraw),
+        }
+
+        // The house's stake went back to the bankroll at the draw
+        if game.vs_house {
+            let bankroll = Self::get_house_bankroll(env.clone());
+            if bankroll < game.player2_points {
+                return Err(Error::InsufficientBankroll);
+            }
+            env.storage()
+                .instance()
+                .set(&DataKey::HouseBankroll, &(bankroll - game.player2_points));
+        }
+
+        // A fresh word: the replay count keeps it from repeating the draw
+        game.replays += 1;
+        Self::seed_prng(&env, session_id, &game.player1, &game.player2, 1, game.replays, SEED_TAG_WORD);
+        let hidden_word_id = Self::select_word_id(&env, game.config.guess_length)?;
+        game.guess_length = Self::guess_length_for(&env, &game.config, hidden_word_id);
+        game.hidden_word_id = hidden_word_id;
+        game.hidden_letters = Self::get_hidden_letters(env.clone(), hidden_word_id);
+        game.oracle_letters = None;
+
+        game.player1_guess = None;
+        game.player2_guess = None;
+        game.player1_guess_commitment = None;
+        game.player2_guess_commitment = None;
+        game.player1_salt = None;
+        game.player2_salt = None;
+        game.player1_confirmed = false;
+        game.player2_confirmed = false;
+
+        game.winner = None;
+        game.result = None;
+        game.breakdown = None;
+        game.settled_at_ledger = None;
+
+        game.current_round = 1;
+        game.p1_round_wins = 0;
+        game.p2_round_wins = 0;
+        game.round_complete = false;
+
+        Self::save_game(&env, &key, &mut game);
+        Self::move_status(&env, Some(STATUS_SETTLED), STATUS_WAITING);
+        Ok(())
+    }
+
+    /* -------------------------------------------- */
+    /* CANCEL GAME                                  */
+    /* -------------------------------------------- */
+    /// Abort a game before anyone has moved and remove it from storage.
+    ///
+    /// The Game Hub has no refund or abort call, and `end_game` always names
+    /// a winner, so nothing is reported: the hub session is left to the
+    /// hub's own expiry.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `caller` - Either player
+    pub fn cancel_game(env: Env, session_id: u32, caller: Address) -> Result<(), Error> {
+        caller.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or_else(|| Self::missing_game_error(&env, session_id))?;
+
+        if caller != game.player1 && caller != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+
+        if game.player1_guess.is_some()
+            || game.player2_guess.is_some()
+            || game.player1_guess_commitment.is_some()
+            || game.player2_guess_commitment.is_some()
+        {
+            return Err(Error::GameAlreadyStarted);
+        }
+
+        // The house's stake goes back to the bankroll
+        if game.vs_house {
+            let bankroll = Self::get_house_bankroll(env.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::HouseBankroll, &(bankroll + game.player2_points));
+        }
+        Self::unindex_game(&env, &game, session_id);
+
+        env.storage().temporary().remove(&key);
+        Self::move_status(&env, Some(Self::status_of(&game)), STATUS_CANCELLED);
+
+        Ok(())
+    }
+
+    /* -------------------------------------------- */
+    /* CLAIM WIN ON TIMEOUT                         */
+    /* -------------------------------------------- */
+    /// Win by default when the opponent stops moving.
+    ///
+    /// Once the move timeout (see `get_move_timeout`) has passed since the
+    /// last move, a player who is further along than the opponent
+    /// (committed while the opponent hasn't, or guessed while the opponent
+    /// hasn't) takes the pot and the result is reported to the hub.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `caller` - The waiting player
+    pub fn claim_timeout_win(env: Env, session_id: u32, caller: Address) -> Result<(), Error> {
+        caller.require_auth();
+
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or_else(|| Self::missing_game_error(&env, session_id))?;
+
+        if game.result.is_some() {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        // 0 = no move yet, 1 = committed, 2 = guessed
+        let progress = |guess: &Option<Vec<u32>>, commitment: &Option<BytesN<32>>| {
+            if guess.is_some() {
+                2
+            } else if commitment.is_some() {
+                1
+            } else {
+                0
+            }
+        };
+        let p1_progress = progress(&game.player1_guess, &game.player1_guess_commitment);
+        // The house only guesses at settlement, so it is never behind
+        let p2_progress = if game.vs_house {
+            2
+        } else {
+            progress(&game.player2_guess, &game.player2_guess_commitment)
+        };
+
+        let result = if caller == game.player1 && p1_progress > p2_progress {
+            GameResult::Player1Win
+        } else if caller == game.player2 && p2_progress > p1_progress {
+            GameResult::Player2Win
+        } else if caller == game.player1 || caller == game.player2 {
+            return Err(Error::OpponentNotBehind);
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        // Measured from the last move, so a slow game can't be claimed early
+        let deadline = game
+            .last_action_ledger
+            .saturating_add(Self::get_move_timeout(env.clone()));
+        if env.ledger().sequence() < deadline {
+            return Err(Error::TimeoutNotReached);
+        }
+        Self::award_by_default(&env, session_id, game, result, ResultReason::Timeout);
+        Ok(())
+    }
+
+    // Settle a game that ends without scoring (timeout or surrender): the
+    // winner takes the pot, whatever the round, and the hub is told at once
+    fn award_by_default(
+        env: &Env,
+        session_id: u32,
+        mut game: Game,
+        result: GameResult,
+        reason: ResultReason,
+    ) {
+        let key = DataKey::Game(session_id);
+        let winner = if result == GameResult::Player1Win {
+            game.player1.clone()
+        } else {
+            game.player2.clone()
+        };
+        Self::move_status(env, Some(Self::status_of(&game)), STATUS_SETTLED);
+        Self::record_recent_settled(env, session_id);
+
+        // Record player results against the stakes before they move
+        Self::record_player_stats(env, &game, result);
+
+        Self::pay_winner(&mut game, result);
+
+        // The house's points, won or refunded, go back to the bankroll
+        if game.vs_house {
+            let bankroll = Self::get_house_bankroll(env.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::HouseBankroll, &(bankroll + game.player2_points));
+        }
+
+        game.breakdown = Some(ResultBreakdown {
+            p1_exact: 0,
+            p1_present: 0,
+            p2_exact: 0,
+            p2_present: 0,
+            winner: Some(winner.clone()),
+            reason,
+        });
+        game.winner = Some(winner.clone());
+        game.result = Some(result);
+        game.settled_at_ledger = Some(env.ledger().sequence());
+        Self::record_word_stats(env, &game, result);
+
+        let game_hub_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set");
+        GameHubClient::new(env, &game_hub_addr)
+            .end_game(&session_id, &(result == GameResult::Player1Win));
+        Self::unindex_game(env, &game, session_id);
+
+        if Self::emits(env, EVENT_LEVEL_LIFECYCLE) {
+            WinnerRevealed {
+                session_id,
+                winner: Some(winner),
+            }
+            .publish(env);
+        }
+
+        if Self::get_auto_purge_on_end(env.clone()) {
+            env.storage().temporary().remove(&key);
+            return;
+        }
+
+        game.ended = true;
+        Self::save_game(env, &key, &mut game);
+    }
+
+    /* -------------------------------------------- */
+    /* SURRENDER                                    */
+    /* -------------------------------------------- */
+    /// Concede the game. The opponent wins and takes the pot immediately,
+    /// and the result is reported to the hub.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The player conceding
+    pub fn surrender(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or_else(|| Self::missing_game_error(&env, session_id))?;
+
+        if game.winner.is_some() || game.result.is_some() {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let result = if player == game.player1 {
+            GameResult::Player2Win
+        } else if player == game.player2 {
+            GameResult::Player1Win
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        Self::award_by_default(&env, session_id, game, result, ResultReason::Surrender);
+        Ok(())
+    }
+
+    /* -------------------------------------------- */
+    /* SESSION KEY DELEGATION                       */
+    /* -------------------------------------------- */
+    /// Let a temporary session key submit guesses and commitments on the
+    /// player's behalf until ledger `expires_at`.
+    ///
+    /// The session key signs too, and a key still delegated by another
+    /// player can't be taken over (`DelegateInUse`) until it expires.
+    ///
+    /// # Arguments
+    /// * `player` - The delegating player
+    /// * `delegate` - The session key address
+    /// * `expires_at` - Last ledger sequence the delegation is valid for
+    pub fn set_delegate(
+        env: Env,
+        player: Address,
+        delegate: Address,
+        expires_at: u32,
+    ) -> Result<(), Error> {
+        player.require_auth();
+        delegate.require_auth();
+
+        let key = DataKey::Delegate(delegate);
+        let existing: Option<Delegation> = env.storage().persistent().get(&key);
+        if let Some(existing) = existing {
+            if existing.player != player && env.ledger().sequence() <= existing.expires_at {
+                return Err(Error::DelegateInUse);
+            }
+        }
+
+        env.storage()
+            .persistent()
+            .set(&key, &Delegation { player, expires_at });
+        Ok(())
+    }
+
+    /// Resolve the acting address to a player of `game` and require its auth.
+    /// A player acts for themselves; any other address must hold an unexpired
+    /// delegation from one of the two players.
+    fn authorize_player(env: &Env, game: &Game, actor: Address) -> Result<Address, Error> {
+        if actor == game.player1 || actor == game.player2 {
+            actor.require_auth();
+            return Ok(actor);
+        }
+
+        let delegation: Delegation = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Delegate(actor.clone()))
+            .ok_or(Error::NotPlayer)?;
+
+        if delegation.player != game.player1 && delegation.player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if env.ledger().sequence() > delegation.expires_at {
+            return Err(Error::DelegateExpired);
+        }
+
+        actor.require_auth();
+        Ok(delegation.player)
+    }
+
+    /* -------------------------------------------- */
+    /* ORACLE WORD REPORT                           */
+    /* -------------------------------------------- */
+    /// Report the letters (A=0..Z=25) an oracle game is scored against.
+    ///
+    /// The word is reported once per round, before either player moves, and
+    /// needs at least `guess_length` distinct letters. Guesses and
+    /// commitments are refused until it is in.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `letters` - The word supplied by the off-chain source
+    /// * `oracle_auth` - The oracle registered for this game
+    pub fn report_word(
+        env: Env,
+        session_id: u32,
+        letters: Vec<u32>,
+        oracle_auth: Address,
+    ) -> Result<(), Error> {
+        oracle_auth.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game = Self::get_game(env.clone(), session_id)?;
+
+        if game.oracle != Some(oracle_auth) {
+            return Err(Error::NotOracle);
+        }
+        if game.result.is_some() {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if game.oracle_letters.is_some() {
+            return Err(Error::WordAlreadyReported);
+        }
+        if game.player1_guess.is_some()
+            || game.player2_guess.is_some()
+            || game.player1_guess_commitment.is_some()
+            || game.player2_guess_commitment.is_some()
+        {
+            return Err(Error::GameAlreadyStarted);
+        }
+
+        if letters.iter().any(|letter| letter > 25) {
+            return Err(Error::InvalidLetter);
+        }
+        if Self::distinct_letters(&letters) < game.guess_length {
+            return Err(Error::InvalidWord);
+        }
+
+        game.oracle_letters = Some(letters);
+        Self::save_game(&env, &key, &mut game);
+        Ok(())
+    }
+
+    /* -------------------------------------------- */
+    /* CONFIRM RESULT                               */
+    /* -------------------------------------------- */
+    /// Accept the provisional winner of a game started with
+    /// `require_confirmation`.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The confirming player
+    pub fn confirm_result(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game = Self::get_game(env.clone(), session_id)?;
+
+        if game.result.is_none() {
+            return Err(Error::GameNotSettled);
+        }
+        if game.ended {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        if player == game.player1 {
+            game.player1_confirmed = true;
+        } else if player == game.player2 {
+            game.player2_confirmed = true;
+        } else {
+            return Err(Error::NotPlayer);
+        }
+
+        Self::save_game(&env, &key, &mut game);
+        Ok(())
+    }
+
+    /* -------------------------------------------- */
+    /* WORD POOL (Frontend Exact Match)             */
+    /* -------------------------------------------- */
+    /// Get the number of words in the pool hidden words are drawn from
+    ///
+    /// # Returns
+    /// * `u32` - Pool size; word IDs run from 0 to this minus one
+    pub fn get_word_count(env: Env) -> u32 {
+        let custom: Option<Vec<String>> = env.storage().persistent().get(&DataKey::Words);
+        match custom {
+            Some(words) => words.len(),
+            None => WORD_POOL.len() as u32,
+        }
+    }
+
+    /// Get the word pool: the admin's list if one was set, otherwise the
+    /// built-in `WORD_POOL`
+    ///
+    /// # Returns
+    /// * `Vec<String>` - The words, indexed by `hidden_word_id`
+    pub fn get_words(env: Env) -> Vec<String> {
+        let custom: Option<Vec<String>> = env.storage().persistent().get(&DataKey::Words);
+        if let Some(words) = custom {
+            return words;
+        }
+
+        let mut words = Vec::new(&env);
+        for word in WORD_POOL {
+            words.push_back(String::from_str(&env, word));
+        }
+        words
+    }
+
+    /// Replace the built-in word pool without an upgrade. New games draw
+    /// from this list; games already started keep the word they drew.
+    ///
+    /// # Arguments
+    /// * `words` - Non-empty list of uppercase words (`A`-`Z`, at most 32 letters)
+    pub fn set_words(env: Env, words: Vec<String>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if words.is_empty() {
+            return Err(Error::InvalidWord);
+        }
+        for word in words.iter() {
+            Self::check_pool_word(&env, &word)?;
+        }
+
+        env.storage().persistent().set(&DataKey::Words, &words);
+        Ok(())
+    }
+
+    /// Append a word to the pool (the built-in words are copied over the
+    /// first time). Existing word IDs are unaffected.
+    ///
+    /// # Arguments
+    /// * `word` - Uppercase word (`A`-`Z`, at most 32 letters) with at least
+    ///   `get_min_distinct_letters` distinct letters
+    pub fn add_word(env: Env, word: String) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        Self::check_pool_word(&env, &word)?;
+
+        let mut words = Self::get_words(env.clone());
+        words.push_back(word);
+        env.storage().persistent().set(&DataKey::Words, &words);
+        Ok(())
+    }
+
+    /// Re-point a game that nobody has moved in yet at another pool word,
+    /// e.g. after `set_words` replaced a deprecated list. There is a single
+    /// pool, so only the word changes.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `new_word_id` - Index into `get_words`
+    pub fn admin_repool_game(env: Env, session_id: u32, new_word_id: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or_else(|| Self::missing_game_error(&env, session_id))?;
+
+        if game.player1_guess.is_some()
+            || game.player2_guess.is_some()
+            || game.player1_guess_commitment.is_some()
+            || game.player2_guess_commitment.is_some()
+        {
+            return Err(Error::GameAlreadyStarted);
+        }
+
+        // The word must exist and still allow a full match
+        if new_word_id >= Self::get_word_count(env.clone()) {
+            return Err(Error::InvalidWord);
+        }
+        let word = Self::get_hidden_letters(env.clone(), new_word_id);
+        if Self::distinct_letters(&word) < game.config.guess_length {
+            return Err(Error::InvalidWord);
+        }
+
+        game.hidden_word_id = new_word_id;
+        game.hidden_letters = word;
+        game.guess_length = Self::guess_length_for(&env, &game.config, new_word_id);
+        Self::save_game(&env, &key, &mut game);
+        Ok(())
+    }
+
+    /// Get the fewest distinct letters a word needs to join the pool
+    ///
+    /// # Returns
+    /// * `u32` - The minimum (0 = no minimum)
+    pub fn get_min_distinct_letters(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinDistinctLetters)
+            .unwrap_or(0)
+    }
+
+    /// Keep degenerate words such as "AAB" out of the pool. Only checked
+    /// when words are added; words already in the pool stay.
+    ///
+    /// # Arguments
+    /// * `min` - Distinct letters required (0 = no minimum)
+    pub fn set_min_distinct_letters(env: Env, min: u32) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MinDistinctLetters, &min);
+    }
+
+    // Pool words must be plain letters with enough distinct ones
+    fn check_pool_word(env: &Env, word: &String) -> Result<(), Error> {
+        let letters = Self::string_letters(env, word).ok_or(Error::InvalidWord)?;
+        if Self::distinct_letters(&letters) < Self::get_min_distinct_letters(env.clone()) {
+            return Err(Error::InvalidWord);
+        }
+        Ok(())
+    }
+
+    /// Get the scoring mode games started without a config use for the
+    /// current word pool
+    ///
+    /// # Returns
+    /// * `ScoringMode` - The pool's default (`Loose` unless set)
+    pub fn get_pool_scoring_mode(env: Env) -> ScoringMode {
+        env.storage()
+            .persistent()
+            .get(&DataKey::WordsScoringMode)
+            .unwrap_or(ScoringMode::Loose)
+    }
+
+    /// Set the default scoring mode for the word pool, e.g. `Exact` for a
+    /// list of long names. `start_game_with_config` still overrides it.
+    ///
+    /// # Arguments
+    /// * `mode` - Scoring mode applied to new games
+    pub fn set_pool_scoring_mode(env: Env, mode: ScoringMode) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::WordsScoringMode, &mode);
+    }
+
+    /// Config for games started without one: the defaults, scored the way
+    /// the word pool asks for
+    fn pool_default_config(env: &Env) -> GameConfig {
+        GameConfig {
+            scoring_mode: Self::get_pool_scoring_mode(env.clone()),
+            ..GameConfig::default()
+        }
+    }
+
+    /// Letters of pool word `id`; IDs past the end wrap around the pool.
+    fn get_hidden_letters(env: Env, id: u32) -> Vec<u32> {
+        let custom: Option<Vec<String>> = env.storage().persistent().get(&DataKey::Words);
+        match custom {
+            Some(words) => {
+                let word = words.get_unchecked(id % words.len());
+                Self::string_letters(&env, &word).expect("Stored word is invalid")
+            }
+            None => Self::encode_word(env, WORD_POOL[id as usize % WORD_POOL.len()]),
+        }
+    }
+
+    /// Letters of an uppercase word string, or `None` if it is empty, longer
+    /// than `MAX_GUESS_STR_LEN` or has characters outside `A`-`Z`.
+    fn string_letters(env: &Env, word: &String) -> Option<Vec<u32>> {
+        let len = word.len() as usize;
+        if len == 0 || len > MAX_GUESS_STR_LEN {
+            return None;
+        }
+
+        let mut buf = [0u8; MAX_GUESS_STR_LEN];
+        word.copy_into_slice(&mut buf[..len]);
+        let text = core::str::from_utf8(&buf[..len]).ok()?;
+        if !text.bytes().all(|b| b.is_ascii_uppercase()) {
+            return None;
+        }
+
+        Some(Self::encode_word(env.clone(), text))
+    }
+
+    /// The word a game is played on: the oracle's letters for oracle games,
+    /// otherwise the pool word.
+    fn game_word(env: &Env, game: &Game) -> Result<Vec<u32>, Error> {
+        if game.oracle.is_some() {
+            return game.oracle_letters.clone().ok_or(Error::WordNotReported);
+        }
+        Ok(game.hidden_letters.clone())
+    }
+
+    /// The letters a game is scored against: the word followed by any
+    /// decoys derived from the game's decoy seed.
+    fn game_hidden_letters(env: &Env, game: &Game) -> Result<Vec<u32>, Error> {
+        let mut hidden = Self::game_word(env, game)?;
+
+        if let Some(seed) = &game.decoy_seed {
+            // Only letters outside the word can be decoys
+            let available = 26 - Self::distinct_letters(&hidden);
+            let count = game.config.decoys.min(available);
+
+            let mut decoys: Vec<u32> = Vec::new(env);
+            let mut block = seed.clone();
+            while decoys.len() < count {
+                block = env.crypto().sha256(&Bytes::from(block)).into();
+                for byte in block.to_array() {
+                    let letter = (byte % 26) as u32;
+                    if decoys.len() < count && !hidden.contains(letter) && !decoys.contains(letter) {
+                        decoys.push_back(letter);
+                    }
+                }
+            }
+            hidden.append(&decoys);
+        }
+
+        Ok(hidden)
+    }
+
+    /// Get the encoded hidden letters (A=0..Z=25) of a settled game,
+    /// so results can be audited against the raw letters.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Vec<u32>` - The hidden letters, or `Error::GameNotSettled` before settlement
+    pub fn get_hidden_letters_public(env: Env, session_id: u32) -> Result<Vec<u32>, Error> {
+        let game = Self::get_game(env.clone(), session_id)?;
+        if game.result.is_none() {
+            return Err(Error::GameNotSettled);
+        }
+
+        Self::game_hidden_letters(&env, &game)
+    }
+
+    /* -------------------------------------------- */
+    /* WORD SELECTION                               */
+    /* -------------------------------------------- */
+    /// Pick the hidden word for a round. Starts at a word drawn from the
+    /// PRNG (seeded by the caller with `seed_prng`) and re-rolls to the next
+    /// word (at most once around the pool) until the word has at least
+    /// `guess_length` distinct letters.
+    fn select_word_id(env: &Env, guess_length: u32) -> Result<u32, Error> {
+        let word_count = Self::get_word_count(env.clone());
+        let start = env.prng().gen_range::<u64>(0..word_count as u64) as u32;
+        for attempt in 0..word_count {
+            let word_id = (start + attempt) % word_count;
+            let word = Self::get_hidden_letters(env.clone(), word_id);
+            if Self::distinct_letters(&word) >= guess_length {
+                return Ok(word_id);
+            }
+        }
+
+        Err(Error::NoSuitableWord)
+    }
+
+    /// Seed the PRNG from the game's inputs, as dice-duel does: never the
+    /// ledger, which differs between simulation and submission. The round,
+    /// replay count and `tag` give each draw of a game its own seed.
+    fn seed_prng(
+        env: &Env,
+        session_id: u32,
+        player1: &Address,
+        player2: &Address,
+        round: u32,
+        replays: u32,
+        tag: u8,
+    ) {
+        let mut seed_bytes = Bytes::new(env);
+        seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        seed_bytes.append(&player1.to_string().to_bytes());
+        seed_bytes.append(&player2.to_string().to_bytes());
+        seed_bytes.append(&Bytes::from_array(env, &round.to_be_bytes()));
+        seed_bytes.append(&Bytes::from_array(env, &replays.to_be_bytes()));
+        seed_bytes.append(&Bytes::from_array(env, &[tag]));
+        let seed: BytesN<32> = env.crypto().keccak256(&seed_bytes).into();
+        env.prng().seed(seed.into());
+    }
+
+    /// Letters per guess for a word. Guesses cover the whole word unless the
+    /// config says otherwise; an oracle's word isn't known yet, so those
+    /// games fall back to the default.
+    fn guess_length_for(env: &Env, config: &GameConfig, hidden_word_id: u32) -> u32 {
+        if config.guess_length > 0 {
+            config.guess_length
+        } else if config.use_oracle {
+            DEFAULT_GUESS_LENGTH
+        } else {
+            Self::get_hidden_letters(env.clone(), hidden_word_id).len()
+        }
+    }
+
+    /// Check a guess has the expected length and only letters A=0..Z=25.
+    fn validate_guess(game: &Game, guess: &Vec<u32>) -> Result<(), Error> {
+        if guess.len() != game.guess_length {
+            return Err(Error::InvalidGuessLength);
+        }
+        if guess.iter().any(|letter| letter > 25) {
+            return Err(Error::InvalidLetter);
+        }
+        Ok(())
+    }
+
+    fn distinct_letters(word: &Vec<u32>) -> u32 {
+        let mut seen = Vec::new(word.env());
+        for letter in word.iter() {
+            if !seen.contains(letter) {
+                seen.push_back(letter);
+            }
+        }
+        seen.len()
+    }
+
+    /* -------------------------------------------- */
+    /* ENCODE WORD → Vec<u32> (A=0..Z=25)           */
+    /* -------------------------------------------- */
+    fn encode_word(env: Env, word: &str) -> Vec<u32> {
+        let mut out = Vec::new(&env);
+
+        for b in word.as_bytes() {
+            let letter = (*b - b'A') as u32;
+            out.push_back(letter);
+        }
+
+        out
+    }
+
+    /* -------------------------------------------- */
+    /* COMMITMENT PREIMAGE                          */
+    /* -------------------------------------------- */
+    // Layout (integers are big-endian):
+    //   salt (32 bytes)
+    //   || session_id (4 bytes)
+    //   || player strkey ("G..."/"C...", 56 ASCII bytes)
+    //   || each guess letter (4 bytes per letter)
+    fn commitment_preimage(
+        env: &Env,
+        session_id: u32,
+        player: &Address,
+        guess: &Vec<u32>,
+        salt: &BytesN<32>,
+    ) -> Bytes {
+        let mut preimage = Bytes::from(salt.clone());
+        preimage.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        preimage.append(&player.to_string().to_bytes());
+        for letter in guess.iter() {
+            preimage.append(&Bytes::from_array(env, &letter.to_be_bytes()));
+        }
+        preimage
+    }
+
+    /* -------------------------------------------- */
+    /* SCORING                                      */
+    /* -------------------------------------------- */
+    /// Count (exact, present) matches of a guess against the hidden word.
+    /// Exact = same letter at the same position; present = letter appears
+    /// elsewhere in the word. Each hidden letter is credited at most once,
+    /// so repeated guess letters only match as many times as the word has them.
+    fn match_counts(hidden: &Vec<u32>, guess: &Vec<u32>) -> (u32, u32) {
+        let mut exact = 0;
+        let mut present = 0;
+
+        // Exact matches first; the unmatched hidden letters stay available
+        let mut remaining = hidden.clone();
+        let mut misplaced = Vec::new(hidden.env());
+        for (i, g) in guess.iter().enumerate() {
+            if hidden.get(i as u32) == Some(g) {
+                exact += 1;
+                if let Some(idx) = remaining.first_index_of(g) {
+                    remaining.remove(idx);
+                }
+            } else {
+                misplaced.push_back(g);
+            }
+        }
+
+        for g in misplaced.iter() {
+            if let Some(idx) = remaining.first_index_of(g) {
+                present += 1;
+                remaining.remove(idx);
+            }
+        }
+
+        (exact, present)
+    }
+
+    fn score_guess(
+        hidden: &Vec<u32>,
+        guess: &Vec<u32>,
+        weights: (u32, u32),
+        mode: ScoringMode,
+    ) -> u32 {
+        let (exact_weight, present_weight) = weights;
+        match mode {
+            ScoringMode::Loose => {
+                let (exact, present) = Self::match_counts(hidden, guess);
+                exact * exact_weight + present * present_weight
+            }
+            ScoringMode::Exact => {
+                // Compare index by index; misplaced letters score nothing
+                let mut exact = 0;
+                for i in 0..guess.len() {
+                    if hidden.get(i).is_some() && hidden.get(i) == guess.get(i) {
+                        exact += 1;
+                    }
+                }
+                exact * exact_weight
+            }
+        }
+    }
+
+    // Weighted scores of both guesses as `reveal_winner` would compute them
+    fn current_scores(env: &Env, game: &Game) -> Result<(u32, u32), Error> {
+        let p1_guess = game.player1_guess.clone().ok_or(Error::BothPlayersNotGuessed)?;
+        let p2_guess = game.player2_guess.clone().ok_or(Error::BothPlayersNotGuessed)?;
+
+        let hidden = Self::game_hidden_letters(env, game)?;
+        let weights = Self::get_score_weights(env.clone());
+        Ok((
+            Self::score_guess(&hidden, &p1_guess, weights, game.config.scoring_mode),
+            Self::score_guess(&hidden, &p2_guess, weights, game.config.scoring_mode),
+        ))
+    }
+
+    // Move the pot to the winner, as the Game Hub will when the result is
+    // reported. A draw leaves both stakes.
+    fn pay_winner(game: &mut Game, result: GameResult) {
+        match result {
+            GameResult::Player1Win => {
+                game.forfeited = game.player2_points;
+                game.player1_points += game.player2_points;
+                game.player2_points = 0;
+            }
+            GameResult::Player2Win => {
+                game.forfeited = game.player1_points;
+                game.player2_points += game.player1_points;
+                game.player1_points = 0;
+            }
+            GameResult::Draw => {}
+        }
+    }
+
+    // Equal scores are a draw; otherwise golf mode flips the comparison
+    // so that fewer correct letters wins
+    fn decide(config: &GameConfig, p1_correct: u32, p2_correct: u32) -> GameResult {
+        if p1_correct == p2_correct {
+            return GameResult::Draw;
+        }
+
+        let player1_ahead = match config.scoring_direction {
+            ScoringDirection::Higher => p1_correct > p2_correct,
+            ScoringDirection::Lower => p1_correct < p2_correct,
+        };
+        if player1_ahead {
+            GameResult::Player1Win
+        } else {
+            GameResult::Player2Win
+        }
+    }
+
+    /* -------------------------------------------- */
+    /* WORD STATS                                   */
+    /* -------------------------------------------- */
+    /// Get aggregate results for a hidden word
+    ///
+    /// # Arguments
+    /// * `word_id` - Index into the word pool
+    ///
+    /// # Returns
+    /// * `WordStats` - Counters over all settled pool games, and every
+    ///   round of a best-of-N match, played on this word
+    pub fn get_word_stats(env: Env, word_id: u32) -> WordStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::WordStats(word_id))
+            .unwrap_or_default()
+    }
+
+    // Count a result against the word in play; best-of-N matches count
+    // every round on its own word
+    fn record_word_stats(env: &Env, game: &Game, result: GameResult) {
+        // Oracle games aren't played on a pool word
+        if game.oracle.is_some() {
+            return;
+        }
+
+        let key = DataKey::WordStats(game.hidden_word_id);
+        let mut stats = Self::get_word_stats(env.clone(), game.hidden_word_id);
+
+        stats.games += 1;
+        match result {
+            GameResult::Player1Win => stats.p1_wins += 1,
+            GameResult::Player2Win => stats.p2_wins += 1,
+            GameResult::Draw => stats.draws += 1,
+        }
+
+        env.storage().persistent().set(&key, &stats);
+    }
+
+    /* -------------------------------------------- */
+    /* STATUS COUNTS                                */
+    /* -------------------------------------------- */
+    /// Get how many games are in each status, for operator dashboards.
+    ///
+    /// Counters are updated as games move between statuses, so nothing is
+    /// scanned. Games that expire unsettled stay counted as waiting.
+    ///
+    /// # Returns
+    /// * `(u32, u32, u32, u32)` - `(waiting_for_guesses, both_guessed, settled, cancelled)`
+    pub fn status_counts(env: Env) -> (u32, u32, u32, u32) {
+        env.storage()
+            .instance()
+            .get(&DataKey::StatusCounts)
+            .unwrap_or((0, 0, 0, 0))
+    }
+
+    fn record_recent_settled(env: &Env, session_id: u32) {
+        let mut recent: Vec<u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::RecentSettled)
+            .unwrap_or(Vec::new(env));
+        recent.push_back(session_id);
+        if recent.len() > RECENT_SETTLED_CAPACITY {
+            recent.pop_front();
+        }
+        env.storage().instance().set(&DataKey::RecentSettled, &recent);
+    }
+
+    fn index_player_game(env: &Env, player: &Address, session_id: u32) {
+        let key = DataKey::PlayerGames(player.clone());
+        let ids: Vec<u32> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+
+        // Drop games that expired without being ended while we're here
+        let mut kept = Vec::new(env);
+        for id in ids.iter() {
+            if env.storage().temporary().has(&DataKey::Game(id)) {
+                kept.push_back(id);
+            }
+        }
+        kept.push_back(session_id);
+        env.storage().persistent().set(&key, &kept);
+    }
+
+    fn unindex_game(env: &Env, game: &Game, session_id: u32) {
+        for player in [&game.player1, &game.player2] {
+            let key = DataKey::PlayerGames(player.clone());
+            let mut ids: Vec<u32> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+            if let Some(index) = ids.first_index_of(session_id) {
+                ids.remove(index);
+                env.storage().persistent().set(&key, &ids);
+            }
+        }
+    }
+
+    fn status_of(game: &Game) -> u32 {
+        if game.result.is_some() {
+            STATUS_SETTLED
+        } else if game.player1_guess.is_some() && game.player2_guess.is_some() {
+            STATUS_BOTH_GUESSED
+        } else {
+            STATUS_WAITING
+        }
+    }
+
+    fn move_status(env: &Env, from: Option<u32>, to: u32) {
+        if from == Some(to) {
+            return;
+        }
+
+        let mut counts = Self::status_counts(env.clone());
+        if let Some(from) = from {
+            let count = Self::status_slot(&mut counts, from);
+            *count = count.saturating_sub(1);
+        }
+        *Self::status_slot(&mut counts, to) += 1;
+
+        env.storage().instance().set(&DataKey::StatusCounts, &counts);
+    }
+
+    fn status_slot(counts: &mut (u32, u32, u32, u32), status: u32) -> &mut u32 {
+        match status {
+            STATUS_WAITING => &mut counts.0,
+            STATUS_BOTH_GUESSED => &mut counts.1,
+            STATUS_SETTLED => &mut counts.2,
+            _ => &mut counts.3,
+        }
+    }
+
+    /* -------------------------------------------- */
+    /* PLAYER PROFILES                              */
+    /* -------------------------------------------- */
+    /// Get a player's lifetime results in one call, for profile pages
+    ///
+    /// # Arguments
+    /// * `player` - The player's address
+    ///
+    /// # Returns
+    /// * `PlayerProfile` - Zeroed for players with no settled games
+    pub fn get_player_profile(env: Env, player: Address) -> PlayerProfile {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PlayerProfile(player))
+            .unwrap_or_default()
+    }
+
+    // The winner takes the loser's stake; call before points are moved
+    fn record_player_stats(env: &Env, game: &Game, result: GameResult) {
+        let (winner, loser, stake) = match result {
+            GameResult::Player1Win => (game.player1.clone(), game.player2.clone(), game.player2_points),
+            GameResult::Player2Win => (game.player2.clone(), game.player1.clone(), game.player1_points),
+            GameResult::Draw => {
+                for player in [&game.player1, &game.player2] {
+                    let mut profile = Self::get_player_profile(env.clone(), player.clone());
+                    profile.draws += 1;
+                    env.storage()
+                        .persistent()
+                        .set(&DataKey::PlayerProfile(player.clone()), &profile);
+                }
+                return;
+            }
+        };
+
+        let mut winner_profile = Self::get_player_profile(env.clone(), winner.clone());
+        winner_profile.wins += 1;
+        winner_profile.pnl += stake;
+        winner_profile.total_won += stake;
+        env.storage()
+            .persistent()
+            .set(&DataKey::PlayerProfile(winner), &winner_profile);
+
+        let mut loser_profile = Self::get_player_profile(env.clone(), loser.clone());
+        loser_profile.losses += 1;
+        loser_profile.pnl -= stake;
+        env.storage()
+            .persistent()
+            .set(&DataKey::PlayerProfile(loser), &loser_profile);
+    }
+
+    /* -------------------------------------------- */
+    /* MISSING GAME ERROR                           */
+    /* -------------------------------------------- */
+    // Session ids need not be sequential, so "at or below the highest id
+    // ever created" is a best-effort signal that the entry expired
+    fn missing_game_error(env: &Env, session_id: u32) -> Error {
+        let max_session: Option<u32> = env.storage().instance().get(&DataKey::MaxSession);
+        match max_session {
+            Some(max) if session_id <= max => Error::GameExpired,
+            _ => Error::GameNotFound,
+        }
+    }
+
+    /* -------------------------------------------- */
+    /* PROOF BINDING INPUTS                         */
+    /* -------------------------------------------- */
+    // Public inputs that follow the winner flag in a settlement proof:
+    //   hidden_word_id
+    //   || player1 commitment (8 big-endian u32 words)
+    //   || player2 commitment (8 big-endian u32 words)
+    fn proof_binding_inputs(env: &Env, game: &Game) -> Vec<u32> {
+        let mut inputs = vec![env, game.hidden_word_id];
+        for commitment in [&game.player1_guess_commitment, &game.player2_guess_commitment]
+            .into_iter()
+            .flatten()
+        {
+            for word in commitment.to_array().chunks(4) {
+                inputs.push_back(u32::from_be_bytes([word[0], word[1], word[2], word[3]]));
+            }
+        }
+        inputs
+    }
+
+    /* -------------------------------------------- */
+    /* COMMITMENT VERIFICATION                      */
+    /* -------------------------------------------- */
+    // Every player who committed must have revealed a guess and salt that
+    // hash back to their commitment
+    fn verify_commitments(env: &Env, session_id: u32, game: &Game) -> Result<(), Error> {
+        let sides = [
+            (&game.player1, &game.player1_guess_commitment, &game.player1_guess, &game.player1_salt),
+            (&game.player2, &game.player2_guess_commitment, &game.player2_guess, &game.player2_salt),
+        ];
+
+        for (player, commitment, guess, salt) in sides {
+            if commitment.is_none() {
+                continue;
+            }
+            let (Some(guess), Some(salt)) = (guess, salt) else {
+                return Err(Error::CommitmentMismatch);
+            };
+            Self::verify_reveal(env, session_id, player, commitment, guess, salt)?;
+        }
+
+        Ok(())
+    }
+
+    // A reveal must hash back to the player's stored commitment
+    fn verify_reveal(
+        env: &Env,
+        session_id: u32,
+        player: &Address,
+        commitment: &Option<BytesN<32>>,
+        guess: &Vec<u32>,
+        salt: &BytesN<32>,
+    ) -> Result<(), Error> {
+        let preimage = Self::commitment_preimage(env, session_id, player, guess, salt);
+        let recomputed: BytesN<32> = env.crypto().sha256(&preimage).into();
+        if commitment.as_ref() != Some(&recomputed) {
+            return Err(Error::CommitmentMismatch);
+        }
+        Ok(())
+    }
+
+    /* -------------------------------------------- */
+    /* SAVE GAME + EXTEND TTL                       */
+    /* -------------------------------------------- */
+    fn save_game(env: &Env, key: &DataKey, game: &mut Game) {
+        game.last_action_ledger = env.ledger().sequence();
+
+        let (_, activity_ttl) = Self::get_ttl_policy(env.clone());
+        env.storage().temporary().set(key, game);
+        env.storage()
+            .temporary()
+            .extend_ttl(key, activity_ttl, activity_ttl);
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Check whether the constructor has run (an admin is stored)
+    ///
+    /// # Returns
+    /// * `bool` - `true` once the contract is initialized; never panics
+    pub fn is_initialized(env: Env) -> bool {
+        env.storage().instance().has(&DataKey::Admin)
+    }
+
+    /// Get the admin and GameHub addresses in one call
+    ///
+    /// # Returns
+    /// * `(Option<Address>, Option<Address>)` - `(admin, hub)`, `None` for
+    ///   either one that is unset; never panics
+    pub fn get_ownership(env: Env) -> (Option<Address>, Option<Address>) {
+        let storage = env.storage().instance();
+        (
+            storage.get(&DataKey::Admin),
+            storage.get(&DataKey::GameHubAddress),
+        )
+    }
+
+    /// Get the current admin address
+    ///
+    /// # Returns
+    /// * `Address` - The admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    /// Set a new admin address
+    ///
+    /// # Arguments
+    /// * `new_admin` - The new admin address
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    /// Get the current GameHub contract address
+    ///
+    /// # Returns
+    /// * `Address` - The GameHub contract address
+    pub fn get_hub(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::GameHubAddress)
+            .expect("GameHub address not set")
+    }
+
+    /// Set a new GameHub contract address
+    ///
+    /// The GameHub interface has no read-only method to probe, so verification
+    /// checks that `new_hub` is an address with a deployed executable. This
+    /// catches typos and undeployed contract IDs before every game breaks.
+    ///
+    /// # Arguments
+    /// * `new_hub` - The new GameHub contract address
+    /// * `verify` - Reject `new_hub` with `Error::InvalidHub` if nothing is deployed there
+    pub fn set_hub(env: Env, new_hub: Address, verify: bool) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if verify && new_hub.executable().is_none() {
+            return Err(Error::InvalidHub);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::GameHubAddress, &new_hub);
+
+        Ok(())
+    }
+
+    /// Get the TTL policy applied to game storage
+    ///
+    /// # Returns
+    /// * `(u32, u32)` - Ledgers a new game is kept for, and the ledgers every
+    ///   later action extends it to (both 30 days unless set by the admin)
+    pub fn get_ttl_policy(env: Env) -> (u32, u32) {
+        env.storage()
+            .instance()
+            .get(&DataKey::TtlPolicy)
+            .unwrap_or((GAME_TTL_LEDGERS, GAME_TTL_LEDGERS))
+    }
+
+    /// Set the TTL policy applied to game storage
+    ///
+    /// # Arguments
+    /// * `default_ledgers` - TTL given to a game when it starts
+    /// * `activity_ledgers` - TTL a game is extended to on every later write
+    pub fn set_ttl_policy(env: Env, default_ledgers: u32, activity_ledgers: u32) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::TtlPolicy, &(default_ledgers, activity_ledgers));
+    }
+
+    /// Get how long a player has to move before the opponent may claim
+    /// the game with `claim_timeout_win`
+    ///
+    /// # Returns
+    /// * `u32` - Timeout in ledgers since the last move
+    pub fn get_move_timeout(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MoveTimeout)
+            .unwrap_or(DEFAULT_MOVE_TIMEOUT_LEDGERS)
+    }
+
+    /// Set how long a player has to move before the opponent may claim
+    /// the game
+    ///
+    /// # Arguments
+    /// * `ledgers` - Timeout in ledgers since the last move
+    pub fn set_move_timeout(env: Env, ledgers: u32) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::MoveTimeout, &ledgers);
+    }
+
+    /// Get the cool-down between a player's consecutive games
+    ///
+    /// # Returns
+    /// * `u32` - Cool-down in ledgers (0 = disabled)
+    pub fn get_cooldown(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Cooldown)
+            .unwrap_or(0)
+    }
+
+    /// Set the cool-down between a player's consecutive games
+    ///
+    /// # Arguments
+    /// * `ledgers` - Minimum ledgers between a player's game starts (0 = disabled)
+    pub fn set_cooldown(env: Env, ledgers: u32) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
 
-    Ok(winner)
-}
+        env.storage().instance().set(&DataKey::Cooldown, &ledgers);
+    }
 
-  /* -------------------------------------------- */
-    /* REVEAL WINNER WITH PROOF                     */
-    /* -------------------------------------------- */
-    pub fn reveal_winner_with_proof(
-    env: Env,
-    session_id: u32,
-    proof: Bytes,
-    public_inputs: Vec<u32>, // winner flag output from Noir
-) -> Result<Address, Error> {
+    /// Get the oracle new `use_oracle` games are bound to
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The registered oracle, if any
+    pub fn get_oracle(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Oracle)
+    }
 
-    // Load game
-    let key = DataKey::Game(session_id);
-    let mut game: Game = env
-        .storage()
-        .temporary()
-        .get(&key)
-        .ok_or(Error::GameNotFound)?;
+    /// Register the oracle new `use_oracle` games are bound to
+    ///
+    /// # Arguments
+    /// * `oracle` - Address allowed to call `report_word`
+    pub fn set_oracle(env: Env, oracle: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
 
-    // Both players must have committed
-    if game.player1_guess_commitment.is_none() || game.player2_guess_commitment.is_none() {
-        panic_with_error!(env, Error::BothPlayersNotGuessed);
+        env.storage().instance().set(&DataKey::Oracle, &oracle);
     }
 
-    // ✅ Prevent double settlement
-    if game.winner.is_some() {
-        panic!("Game already settled");
+    /// Get the house address solo games are played against
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The house, if one has been set
+    pub fn get_house(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::House)
     }
 
-    // ---------------------------------------------------
-    // ✅ Step 1: Verify proof (OFF-CHAIN for now)
-    // ---------------------------------------------------
-    if proof.len() == 0 {
-        panic!("Proof missing");
+    /// Set the house address `start_vs_house` games are played against
+    ///
+    /// # Arguments
+    /// * `house` - Address reported to the hub as player2
+    pub fn set_house(env: Env, house: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::House, &house);
     }
 
-    // ---------------------------------------------------
-    // ✅ Step 2: Extract winner from Noir public output
-    // ---------------------------------------------------
-    //
-    // Noir circuit outputs:
-    // winner_flag = 1 → player1 wins
-    // winner_flag = 2 → player2 wins
-    //
-    if public_inputs.len() < 1 {
-        panic!("Missing public winner output");
+    /// Get the points available for the house to stake
+    ///
+    /// # Returns
+    /// * `i128` - The house bankroll (0 until funded)
+    pub fn get_house_bankroll(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::HouseBankroll)
+            .unwrap_or(0)
     }
 
-    let winner_flag = public_inputs.get(0).unwrap();
+    /// Add points to the house bankroll.
+    ///
+    /// The bankroll is a points ledger only: no tokens are transferred, it
+    /// just caps the stakes the house will match in `start_vs_house`.
+    ///
+    /// # Arguments
+    /// * `amount` - Points to add (must be positive)
+    pub fn fund_house(env: Env, amount: i128) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
 
-    let winner: Address = if winner_flag == 1 {
-        game.player1.clone()
-    } else if winner_flag == 2 {
-        game.player2.clone()
-    } else {
-        panic!("Invalid winner flag");
-    };
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
 
-    // ---------------------------------------------------
-    // ✅ Step 3: Save winner on-chain
-    // ---------------------------------------------------
+        let bankroll = Self::get_house_bankroll(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::HouseBankroll, &(bankroll + amount));
+        Ok(())
+    }
 
-    if winner == game.player1 {
+    /// Get the proof verifier contract address
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The verifier, if one has been set
+    pub fn get_verifier(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Verifier)
+    }
 
-    // Player1 wins → take player2 points
-    game.player1_points += game.player2_points;
-    game.player2_points = 0;
+    /// Set the contract `reveal_winner_with_proof` verifies proofs with
+    ///
+    /// # Arguments
+    /// * `verifier` - Address of a contract implementing `Verifier`
+    pub fn set_verifier(env: Env, verifier: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
 
-} else if winner == game.player2 {
+        env.storage().instance().set(&DataKey::Verifier, &verifier);
+    }
 
-    // Player2 wins → take player1 points
-    game.player2_points += game.player1_points;
-    game.player1_points = 0;
+    /// Check whether ended games are removed from storage
+    ///
+    /// # Returns
+    /// * `bool` - `true` if `end_game` deletes the game (default `false`)
+    pub fn get_auto_purge_on_end(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::AutoPurgeOnEnd)
+            .unwrap_or(false)
+    }
 
-} else {
-    panic!("Winner address does not match players");
-}
+    /// Remove games from storage once `end_game` has reported them, to save
+    /// storage. Results remain in the persistent player profiles.
+    ///
+    /// # Arguments
+    /// * `enabled` - `true` to purge games on end
+    pub fn set_auto_purge_on_end(env: Env, enabled: bool) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
 
-    game.winner = Some(winner.clone());
-    env.storage().temporary().set(&key, &game);
+        env.storage()
+            .instance()
+            .set(&DataKey::AutoPurgeOnEnd, &enabled);
+    }
 
-    Ok(winner)
-}
+    /// Check whether games may only be started with a reserved session ID
+    ///
+    /// # Returns
+    /// * `bool` - `true` if `start_game` requires a `reserve_session` ID
+    pub fn is_reservation_required(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::RequireReservation)
+            .unwrap_or(false)
+    }
 
+    /// Require (or stop requiring) session IDs to come from
+    /// `reserve_session`
+    ///
+    /// # Arguments
+    /// * `required` - `true` to reject unreserved session IDs
+    pub fn set_require_reservation(env: Env, required: bool) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
 
- //  /* -------------------------------------------- */
-    /* END GAME AND REPORT TO HUB                   */
-    /* -------------------------------------------- */
-pub fn end_game(env: Env, session_id: u32, caller: Address) -> Result<(), Error> {
+        env.storage()
+            .instance()
+            .set(&DataKey::RequireReservation, &required);
+    }
 
-    // Caller must sign
-    caller.require_auth();
+    /// Get which events are published
+    ///
+    /// # Returns
+    /// * `u32` - 0 = none, 1 = lifecycle only (default), 2 = verbose
+    pub fn get_event_level(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::EventLevel)
+            .unwrap_or(EVENT_LEVEL_LIFECYCLE)
+    }
 
-    let key = DataKey::Game(session_id);
+    /// Trade event detail for cost. Lifecycle publishes `WinnerRevealed`;
+    /// verbose adds `GuessMade` and `GuessCommitted`.
+    ///
+    /// # Arguments
+    /// * `level` - 0 = none, 1 = lifecycle only, 2 = verbose
+    pub fn set_event_level(env: Env, level: u32) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
 
-    let game: Game = env
-        .storage()
-        .temporary()
-        .get(&key)
-        .ok_or(Error::GameNotFound)?;
+        env.storage().instance().set(&DataKey::EventLevel, &level);
+    }
 
-           // Both players must have committed
-    if game.player1_guess_commitment.is_none() || game.player2_guess_commitment.is_none() {
-        panic_with_error!(env, Error::BothPlayersNotGuessed);
+    fn emits(env: &Env, level: u32) -> bool {
+        let configured = Self::get_event_level(env.clone());
+        configured != EVENT_LEVEL_NONE && configured >= level
     }
 
+    /// Check whether the contract is paused
+    ///
+    /// # Returns
+    /// * `bool` - `true` if starting, guessing, committing and revealing
+    ///   are halted
+    pub fn is_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false)
+    }
 
-    // Ensure winner exists
-    let winner = game.winner.clone().ok_or(Error::BothPlayersNotGuessed)?;
+    /// Halt or resume play during an incident. While paused, new games,
+    /// guesses, commitments and reveals fail with `ContractPaused`; queries
+    /// and admin functions keep working.
+    ///
+    /// # Arguments
+    /// * `paused` - `true` to halt play
+    pub fn set_paused(env: Env, paused: bool) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
 
-    let game_hub_addr: Address = env
-        .storage()
-        .instance()
-        .get(&DataKey::GameHubAddress)
-        .expect("GameHub address not set");
+        env.storage().instance().set(&DataKey::Paused, &paused);
+    }
 
-    let game_hub = GameHubClient::new(&env, &game_hub_addr);
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if Self::is_paused(env.clone()) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
 
-    let player1_won = winner == game.player1;
+    /// Check whether new games are blocked while existing ones drain
+    ///
+    /// # Returns
+    /// * `bool` - `true` if `start_game` and friends are disabled
+    pub fn is_drain_mode(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::DrainMode)
+            .unwrap_or(false)
+    }
 
-    game_hub.end_game(&session_id, &player1_won);
+    /// Block or allow new games. Unlike a full pause, guesses, commits,
+    /// reveals and settlement of games already started keep working.
+    ///
+    /// # Arguments
+    /// * `enabled` - `true` to stop new games
+    pub fn set_drain_mode(env: Env, enabled: bool) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
 
-    Ok(())
-}
+        env.storage().instance().set(&DataKey::DrainMode, &enabled);
+    }
 
+    /// Get the cap on a game's combined stake
+    ///
+    /// # Returns
+    /// * `Option<i128>` - Maximum `player1_points + player2_points`, `None` = no cap
+    pub fn get_max_pot(env: Env) -> Option<i128> {
+        env.storage().instance().get(&DataKey::MaxPot)
+    }
 
-    /* -------------------------------------------- */
-    /* FULL 50 WORD POOL (Frontend Exact Match)     */
-    /* -------------------------------------------- */
-    fn get_hidden_letters(env: Env, id: u32) -> Vec<u32> {
-        let word = match id {
-            0 => "APPLE",
-            1 => "BANANA",
-            2 => "ORANGE",
-            3 => "GRAPE",
-            4 => "MANGO",
-            5 => "PEACH",
-            6 => "LEMON",
-            7 => "CHERRY",
-            8 => "PEAR",
-            9 => "PLUM",
-            10 => "KIWI",
-            11 => "FIG",
-            12 => "DATE",
-            13 => "LIME",
-            14 => "APRICOT",
-            15 => "PAPAYA",
-            16 => "GUAVA",
-            17 => "PINEAPPLE",
-            18 => "COCONUT",
-            19 => "BLUEBERRY",
-            20 => "STRAWBERRY",
-            21 => "RASPBERRY",
-            22 => "BLACKBERRY",
-            23 => "WATERMELON",
-            24 => "CANTALOUPE",
-            25 => "HONEYDEW",
-            26 => "NECTARINE",
-            27 => "TANGERINE",
-            28 => "POMEGRANATE",
-            29 => "PASSIONFRUIT",
-            30 => "DRAGONFRUIT",
-            31 => "LYCHEE",
-            32 => "JACKFRUIT",
-            33 => "CRANBERRY",
-            34 => "MULBERRY",
-            35 => "FIGS",
-            36 => "DATEFRUIT",
-            37 => "OLIVE",
-            38 => "QUINCE",
-            39 => "KUMQUAT",
-            40 => "AVOCADO",
-            41 => "MANDARIN",
-            42 => "PEPPERMINT",
-            43 => "CLEMENTINE",
-            44 => "GRAPEFRUIT",
-            45 => "STARFRUIT",
-            46 => "BILBERRY",
-            47 => "GOOSEBERRY",
-            48 => "ELDERBERRY",
-            _ => "SATSUMA",
-        };
+    /// Set the cap on a game's combined stake
+    ///
+    /// # Arguments
+    /// * `max_pot` - Maximum `player1_points + player2_points` for new games
+    pub fn set_max_pot(env: Env, max_pot: i128) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
 
-        Self::encode_word(env, word)
+        env.storage().instance().set(&DataKey::MaxPot, &max_pot);
     }
 
-    /* -------------------------------------------- */
-    /* ENCODE WORD → Vec<u32> (A=0..Z=25)           */
-    /* -------------------------------------------- */
-    fn encode_word(env: Env, word: &str) -> Vec<u32> {
-        let mut out = Vec::new(&env);
+    /// Get the most rounds a new best-of-N match may have
+    ///
+    /// # Returns
+    /// * `u32` - Round cap (9 unless set)
+    pub fn get_max_rounds(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxRounds)
+            .unwrap_or(DEFAULT_MAX_ROUNDS)
+    }
 
-        for b in word.as_bytes() {
-            let letter = (*b - b'A') as u32;
-            out.push_back(letter);
-        }
+    /// Set the most rounds a new best-of-N match may have
+    ///
+    /// # Arguments
+    /// * `max_rounds` - Highest `GameConfig::rounds` accepted by `start_game`
+    pub fn set_max_rounds(env: Env, max_rounds: u32) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
 
-        out
+        env.storage().instance().set(&DataKey::MaxRounds, &max_rounds);
     }
 
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
-
-    /// Get the current admin address
+    /// Get the maximum number of games that may start in one ledger
     ///
     /// # Returns
-    /// * `Address` - The admin address
-    pub fn get_admin(env: Env) -> Address {
+    /// * `u32` - Per-ledger cap (0 = unlimited)
+    pub fn get_max_games_per_ledger(env: Env) -> u32 {
         env.storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+            .get(&DataKey::MaxGamesPerLedger)
+            .unwrap_or(0)
     }
 
-    /// Set a new admin address
+    /// Set the maximum number of games that may start in one ledger
     ///
     /// # Arguments
-    /// * `new_admin` - The new admin address
-    pub fn set_admin(env: Env, new_admin: Address) {
+    /// * `max_games` - Per-ledger cap (0 = unlimited)
+    pub fn set_max_games_per_ledger(env: Env, max_games: u32) {
         let admin: Address = env
             .storage()
             .instance()
@@ -552,25 +3812,29 @@ pub fn end_game(env: Env, session_id: u32, caller: Address) -> Result<(), Error>
             .expect("Admin not set");
         admin.require_auth();
 
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxGamesPerLedger, &max_games);
     }
 
-    /// Get the current GameHub contract address
+    /// Get the scoring weights used by `reveal_winner`
     ///
     /// # Returns
-    /// * `Address` - The GameHub contract address
-    pub fn get_hub(env: Env) -> Address {
+    /// * `(u32, u32)` - Points per exact-position match and per
+    ///   present-but-misplaced match (both 1 unless set by the admin)
+    pub fn get_score_weights(env: Env) -> (u32, u32) {
         env.storage()
             .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+            .get(&DataKey::ScoreWeights)
+            .unwrap_or((1, 1))
     }
 
-    /// Set a new GameHub contract address
+    /// Set the scoring weights used by `reveal_winner`
     ///
     /// # Arguments
-    /// * `new_hub` - The new GameHub contract address
-    pub fn set_hub(env: Env, new_hub: Address) {
+    /// * `exact` - Points per letter in the right position
+    /// * `present` - Points per letter in the word but in the wrong position
+    pub fn set_score_weights(env: Env, exact: u32, present: u32) {
         let admin: Address = env
             .storage()
             .instance()
@@ -580,7 +3844,7 @@ pub fn end_game(env: Env, session_id: u32, caller: Address) -> Result<(), Error>
 
         env.storage()
             .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+            .set(&DataKey::ScoreWeights, &(exact, present));
     }
 
     /// Update the contract WASM hash (upgrade contract)